@@ -0,0 +1,32 @@
+use std::{
+    cmp::{max, min},
+    ops::RangeInclusive,
+};
+
+/// Every unordered pair of distinct elements of `v`, in order.
+pub fn uniq_pairs<T: Copy>(v: &[T]) -> Vec<(T, T)> {
+    let mut pairs = Vec::new();
+    for lim in 0..v.len() {
+        for s in (lim + 1)..v.len() {
+            pairs.push((v[lim], v[s]));
+        }
+    }
+    pairs
+}
+
+#[test]
+fn test_uniq_pairs() {
+    assert_eq!(uniq_pairs(&[1, 2, 3]), vec![(1, 2), (1, 3), (2, 3)]);
+}
+
+/// The values in both `a` and `b`, if any.
+pub fn intersect(a: &RangeInclusive<i64>, b: &RangeInclusive<i64>) -> Option<RangeInclusive<i64>> {
+    let intersection = max(*a.start(), *b.start())..=min(*a.end(), *b.end());
+    (!intersection.is_empty()).then_some(intersection)
+}
+
+#[test]
+fn test_intersect() {
+    assert_eq!(intersect(&(1..=5), &(3..=8)), Some(3..=5));
+    assert_eq!(intersect(&(1..=5), &(6..=8)), None);
+}
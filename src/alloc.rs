@@ -0,0 +1,113 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+use advent_of_code_2023::days::Day;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting what goes through it.
+struct Counting;
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn grow(size: usize) {
+    ALLOCS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let live = LIVE.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+
+    /// Counted as a new allocation of `new_size` replacing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+            grow(new_size);
+        }
+        new
+    }
+}
+
+/// Allocations made while running one step.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct AllocStats {
+    pub allocs: usize,
+    pub bytes: usize,
+    /// Highest live bytes above what was live when the step started
+    pub peak_bytes: usize,
+}
+
+/// Runs `f`, counting its allocations. Steps must not overlap.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let allocs = ALLOCS.load(Relaxed);
+    let bytes = BYTES.load(Relaxed);
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+    let res = f();
+    let stats = AllocStats {
+        allocs: ALLOCS.load(Relaxed) - allocs,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (res, stats)
+}
+
+/// Counts the allocations of `day`'s parse and parts on its embedded input.
+pub fn count(day: &Day) -> Result<[AllocStats; 3], String> {
+    let (parsed, parse) = measure(|| (day.parse)(day.input));
+    let parsed = parsed.map_err(|e| format!("day{:02}: {e}", day.number))?;
+    let (_, part1) = measure(|| parsed.part1());
+    let (_, part2) = measure(|| parsed.part2());
+    Ok([parse, part1, part2])
+}
+
+pub fn bytes(n: usize) -> String {
+    match n {
+        n if n >= 1 << 20 => format!("{:.1} MiB", n as f64 / (1 << 20) as f64),
+        n if n >= 1 << 10 => format!("{:.1} KiB", n as f64 / (1 << 10) as f64),
+        n => format!("{n} B"),
+    }
+}
+
+#[test]
+fn test_measure() {
+    let (v, stats) = measure(|| {
+        let mut v = Vec::with_capacity(1000);
+        v.push(1u8);
+        drop(vec![0u8; 3000]);
+        v
+    });
+    // Other tests allocate at the same time, so these are only lower bounds
+    assert!(stats.allocs >= 2);
+    assert!(stats.bytes >= 4000);
+    assert_eq!(bytes(4000), "3.9 KiB");
+    drop(v);
+}
@@ -0,0 +1,286 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2023::coords::Coords;
+use advent_of_code_2023::observe::Step;
+use advent_of_code_2023::render::{heat, Rgb};
+
+const FRONTIER: Rgb = [250, 220, 60];
+const QUEUED: Rgb = [110, 90, 30];
+const MARKED: Rgb = [60, 200, 90];
+
+/// What a search did to a tile so far.
+#[derive(Clone, Copy, Default)]
+struct Tile {
+    depth: Option<i64>,
+    queued: bool,
+    marked: bool,
+}
+
+/// A search replayed in the terminal, a frame per round.
+pub struct Animation {
+    glyphs: Vec<Vec<char>>,
+    tiles: Vec<Vec<Tile>>,
+    /// Tiles visited during the current round
+    frontier: Vec<Coords>,
+    deepest: i64,
+    round: usize,
+    name: &'static str,
+    fps: f64,
+    paused: bool,
+    quit: bool,
+    started: bool,
+    keys: Option<keys::RawMode>,
+}
+
+impl Animation {
+    pub fn new(input: &str, name: &'static str, fps: f64) -> Self {
+        let glyphs = input
+            .lines()
+            .map(|l| l.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Animation {
+            tiles: glyphs
+                .iter()
+                .map(|l| vec![Tile::default(); l.len()])
+                .collect(),
+            glyphs,
+            frontier: Vec::new(),
+            deepest: 0,
+            round: 0,
+            name,
+            fps,
+            paused: false,
+            quit: false,
+            started: false,
+            keys: None,
+        }
+    }
+
+    /// Clears the screen and reads keys as they are pressed, when stdin is a terminal.
+    pub fn start(&mut self) {
+        self.keys = keys::RawMode::enable();
+        self.started = true;
+        print!("\x1b[2J\x1b[?25l");
+    }
+
+    fn tile(&mut self, at: Coords) -> Option<&mut Tile> {
+        self.tiles
+            .get_mut(usize::try_from(at.line).ok()?)?
+            .get_mut(usize::try_from(at.col).ok()?)
+    }
+
+    pub fn step(&mut self, step: Step) {
+        match step {
+            Step::Visit { at, depth } => {
+                if let Some(tile) = self.tile(at) {
+                    tile.depth = Some(depth);
+                    self.deepest = self.deepest.max(depth);
+                    self.frontier.push(at);
+                }
+            }
+            Step::Queue(at) => {
+                if let Some(tile) = self.tile(at) {
+                    tile.queued = true;
+                }
+            }
+            Step::Mark(at) => {
+                if let Some(tile) = self.tile(at) {
+                    tile.marked = true;
+                }
+            }
+            Step::Round => {
+                self.round += 1;
+                if !self.quit {
+                    self.show();
+                }
+                self.frontier.clear();
+            }
+        }
+    }
+
+    fn color(&self, at: Coords, tile: Tile) -> Option<Rgb> {
+        if self.frontier.contains(&at) {
+            Some(FRONTIER)
+        } else if tile.marked {
+            Some(MARKED)
+        } else if let Some(depth) = tile.depth {
+            Some(heat(depth, self.deepest))
+        } else {
+            tile.queued.then_some(QUEUED)
+        }
+    }
+
+    fn frame(&self) -> String {
+        let mut frame = String::from("\x1b[H");
+        for (line, (glyphs, tiles)) in self.glyphs.iter().zip(&self.tiles).enumerate() {
+            let mut current = None;
+            for (col, (&glyph, &tile)) in glyphs.iter().zip(tiles).enumerate() {
+                let at = Coords {
+                    line: line as i64,
+                    col: col as i64,
+                };
+                let color = self.color(at, tile);
+                if color != current {
+                    match color {
+                        Some([r, g, b]) => write!(frame, "\x1b[30;48;2;{r};{g};{b}m").unwrap(),
+                        None => frame.push_str("\x1b[0m"),
+                    }
+                    current = color;
+                }
+                frame.push(glyph);
+            }
+            frame.push_str("\x1b[0m\x1b[K\n");
+        }
+        let state = if self.quit {
+            "done"
+        } else if self.paused {
+            "paused"
+        } else {
+            "running"
+        };
+        writeln!(
+            frame,
+            "{} round {}, {state} at {} fps  [space] pause  [n] step  [+/-] speed  [q] skip to the end\x1b[K",
+            self.name, self.round, self.fps
+        )
+        .unwrap();
+        frame
+    }
+
+    /// Draws the frame, then waits for the next one, or for a key while paused.
+    fn show(&mut self) {
+        let mut out = io::stdout().lock();
+        // A closed stdout only loses the animation
+        let _ = out
+            .write_all(self.frame().as_bytes())
+            .and_then(|_| out.flush());
+        let shown = Instant::now();
+        loop {
+            match self.keys.as_ref().and_then(keys::RawMode::key) {
+                Some(b' ') => {
+                    self.paused = !self.paused;
+                    let _ = out
+                        .write_all(self.frame().as_bytes())
+                        .and_then(|_| out.flush());
+                }
+                Some(b'n') if self.paused => return,
+                Some(b'+') => self.fps = (self.fps * 2.0).min(1000.0),
+                Some(b'-') => self.fps = (self.fps / 2.0).max(0.25),
+                Some(b'q') => {
+                    self.quit = true;
+                    return;
+                }
+                _ => {}
+            }
+            if !self.paused && shown.elapsed().as_secs_f64() >= 1.0 / self.fps {
+                return;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// Draws the last frame.
+    pub fn finish(mut self) {
+        self.quit = true;
+        print!("{}", self.frame());
+    }
+}
+
+impl Drop for Animation {
+    fn drop(&mut self) {
+        if self.started {
+            print!("\x1b[0m\x1b[?25h");
+        }
+    }
+}
+
+#[cfg(unix)]
+mod keys {
+    use std::mem::MaybeUninit;
+
+    /// The terminal without line buffering nor echo, so that keys are read as they are
+    /// pressed, set back when dropped.
+    pub struct RawMode(libc::termios);
+
+    impl RawMode {
+        pub fn enable() -> Option<Self> {
+            // SAFETY: isatty only looks at the descriptor
+            if unsafe { libc::isatty(libc::STDIN_FILENO) } == 0 {
+                return None;
+            }
+            let mut saved = MaybeUninit::<libc::termios>::uninit();
+            // SAFETY: tcgetattr only writes into the struct it is given
+            if unsafe { libc::tcgetattr(libc::STDIN_FILENO, saved.as_mut_ptr()) } != 0 {
+                return None;
+            }
+            // SAFETY: tcgetattr succeeded, so it filled the struct
+            let saved = unsafe { saved.assume_init() };
+            let mut raw = saved;
+            raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+            // Reads return at once, with or without a key
+            raw.c_cc[libc::VMIN] = 0;
+            raw.c_cc[libc::VTIME] = 0;
+            // SAFETY: `raw` is a valid termios, read from the same terminal
+            if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) } != 0 {
+                return None;
+            }
+            Some(RawMode(saved))
+        }
+
+        /// The next key pressed, if any.
+        pub fn key(&self) -> Option<u8> {
+            let mut key = 0u8;
+            // SAFETY: reads at most one byte into `key`
+            let n = unsafe { libc::read(libc::STDIN_FILENO, (&mut key as *mut u8).cast(), 1) };
+            (n == 1).then_some(key)
+        }
+    }
+
+    impl Drop for RawMode {
+        fn drop(&mut self) {
+            // SAFETY: puts back the settings read in `enable`
+            unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.0) };
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod keys {
+    /// Keys can't be read without line buffering here, so the animation just plays.
+    pub struct RawMode;
+
+    impl RawMode {
+        pub fn enable() -> Option<Self> {
+            None
+        }
+
+        pub fn key(&self) -> Option<u8> {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_frame() {
+    let mut animation = Animation::new("ab\ncd\n", "bfs", 1000.0);
+    let at = |line, col| Coords { line, col };
+    animation.step(Step::Visit {
+        at: at(0, 0),
+        depth: 0,
+    });
+    animation.step(Step::Queue(at(0, 1)));
+    animation.step(Step::Mark(at(1, 1)));
+    animation.step(Step::Visit {
+        at: at(9, 9),
+        depth: 1,
+    });
+    let frame = animation.frame();
+    assert!(frame.starts_with("\x1b[H\x1b[30;48;2;250;220;60ma\x1b[30;48;2;110;90;30mb\x1b[0m"));
+    assert!(frame.contains("\nc\x1b[30;48;2;60;200;90md"));
+    assert!(frame.contains("bfs round 0, running"));
+}
@@ -0,0 +1,221 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Outcome;
+use advent_of_code_2023::days::Day;
+use advent_of_code_2023::solution::Answer;
+
+/// What is known about the answer to one part of a day.
+#[derive(Serialize, Deserialize, Default)]
+pub struct PartAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<Answer>,
+    /// Smallest answer the site said was too high
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<Answer>,
+    /// Largest answer the site said was too low
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<Answer>,
+}
+
+impl PartAnswers {
+    /// Whether `answer` was rejected, or is past an answer that was too high or too low.
+    pub fn rules_out(&self, answer: Answer) -> bool {
+        self.wrong.contains(&answer)
+            || self.too_high.is_some_and(|h| answer >= h)
+            || self.too_low.is_some_and(|l| answer <= l)
+    }
+}
+
+/// Contents of `answers.toml`, keyed by `dayNN` then `partN`.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct AnswerStore(BTreeMap<String, BTreeMap<String, PartAnswers>>);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    KnownWrong,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Wrong { .. } | Verdict::KnownWrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Verdict::KnownWrong => write!(f, "WRONG, ruled out by rejected answers"),
+            Verdict::Unknown => write!(f, "no accepted answer yet"),
+        }
+    }
+}
+
+const HEADER: &str = "# Accepted answer for each day and part of the puzzle input,
+# and answers already rejected by the site.
+
+";
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+impl AnswerStore {
+    /// Loads the store at `path`, or an empty one if it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(AnswerStore::default());
+        }
+        let data = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        toml::from_str(&data).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let data = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, format!("{HEADER}{data}")).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&PartAnswers> {
+        self.0
+            .get(&format!("day{day:02}"))?
+            .get(&format!("part{part}"))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(PartAnswers {
+                answer: Some(expected),
+                ..
+            }) if *expected == answer => Verdict::Correct,
+            Some(PartAnswers {
+                answer: Some(expected),
+                ..
+            }) => Verdict::Wrong {
+                expected: *expected,
+            },
+            Some(p) if p.rules_out(answer) => Verdict::KnownWrong,
+            _ => Verdict::Unknown,
+        }
+    }
+
+    /// Remembers what the site said about `answer`.
+    ///
+    /// Returns whether the store changed.
+    pub fn record(&mut self, day: u8, part: u8, answer: Answer, outcome: &Outcome) -> bool {
+        let p = self
+            .0
+            .entry(format!("day{day:02}"))
+            .or_default()
+            .entry(format!("part{part}"))
+            .or_default();
+        match outcome {
+            Outcome::Correct => p.answer = Some(answer),
+            Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow => {
+                if !p.wrong.contains(&answer) {
+                    p.wrong.push(answer);
+                    p.wrong.sort_unstable();
+                }
+                match outcome {
+                    Outcome::TooHigh => {
+                        p.too_high = Some(p.too_high.map_or(answer, |h| h.min(answer)))
+                    }
+                    Outcome::TooLow => {
+                        p.too_low = Some(p.too_low.map_or(answer, |l| l.max(answer)))
+                    }
+                    _ => {}
+                }
+            }
+            Outcome::RateLimited { .. } | Outcome::AlreadySolved => return false,
+        }
+        true
+    }
+}
+
+/// Solves both parts of `day` on its embedded input and checks them against `store`.
+pub fn verify_day(day: &Day, store: &AnswerStore) -> Result<[(Answer, Verdict); 2], String> {
+    let parsed = (day.parse)(day.input).map_err(|e| format!("day{:02}: {e}", day.number))?;
+    let part1 = parsed.part1();
+    let part2 = parsed.part2();
+    Ok([
+        (part1, store.check(day.number, 1, part1)),
+        (part2, store.check(day.number, 2, part2)),
+    ])
+}
+
+#[test]
+fn test_check() {
+    let store: AnswerStore = toml::from_str(
+        "[day07.part2]
+answer = 244848487
+wrong = [244990514]",
+    )
+    .unwrap();
+    assert_eq!(store.check(7, 2, 244848487), Verdict::Correct);
+    assert_eq!(
+        store.check(7, 2, 1),
+        Verdict::Wrong {
+            expected: 244848487
+        }
+    );
+    assert_eq!(store.check(7, 1, 1), Verdict::Unknown);
+    let store: AnswerStore = toml::from_str("[day07.part2]\nwrong = [244990514]").unwrap();
+    assert_eq!(store.check(7, 2, 244990514), Verdict::KnownWrong);
+}
+
+#[test]
+fn test_record() {
+    let mut store = AnswerStore::default();
+    store.record(7, 2, 244990514, &Outcome::TooHigh);
+    store.record(7, 2, 1, &Outcome::TooLow);
+    assert_eq!(store.check(7, 2, 244990514), Verdict::KnownWrong);
+    assert_eq!(store.check(7, 2, 300000000), Verdict::KnownWrong);
+    assert_eq!(store.check(7, 2, 0), Verdict::KnownWrong);
+    assert_eq!(store.check(7, 2, 244848487), Verdict::Unknown);
+    assert!(!store.record(
+        7,
+        2,
+        244848487,
+        &Outcome::RateLimited {
+            wait: Default::default()
+        }
+    ));
+    store.record(7, 2, 244848487, &Outcome::Correct);
+    assert_eq!(store.check(7, 2, 244848487), Verdict::Correct);
+    assert_eq!(
+        toml::to_string(&store).unwrap(),
+        "[day07.part2]
+answer = 244848487
+wrong = [1, 244990514]
+too_high = 244990514
+too_low = 1
+"
+    );
+}
+
+#[test]
+fn test_real_answers() {
+    let store = AnswerStore::load(&default_path()).unwrap();
+    for day in advent_of_code_2023::days::DAYS {
+        for (part, (answer, verdict)) in verify_day(day, &store).unwrap().iter().enumerate() {
+            assert!(
+                !verdict.is_failure(),
+                "day{:02} part{} {answer}: {verdict}",
+                day.number,
+                part + 1
+            );
+        }
+    }
+}
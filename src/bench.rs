@@ -0,0 +1,176 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use advent_of_code_2023::days::Day;
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+    /// Median slowdown, in percent, above which a step is flagged as a regression
+    pub threshold: f64,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct Stats {
+    pub median_ns: f64,
+    pub min_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        let ns = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mid = ns.len() / 2;
+        let median_ns = if ns.len() % 2 == 0 {
+            (ns[mid - 1] + ns[mid]) / 2.0
+        } else {
+            ns[mid]
+        };
+        let mean = ns.iter().sum::<f64>() / ns.len() as f64;
+        let variance = ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / ns.len() as f64;
+        Stats {
+            median_ns,
+            min_ns: ns[0],
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Baseline {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+fn default_baseline_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("aoc-bench")
+        .join(format!("day{day:02}.json"))
+}
+
+fn load_baseline(path: &Path) -> Result<Option<Baseline>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let data = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    serde_json::from_str(&data)
+        .map(Some)
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn save_baseline(path: &Path, baseline: &Baseline) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    }
+    let data = serde_json::to_string_pretty(baseline).map_err(|e| e.to_string())?;
+    fs::write(path, data).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn measure(day: &Day, input: &str, samples: Option<&mut [Vec<Duration>; 3]>) -> Result<(), String> {
+    let start = Instant::now();
+    let parsed = (day.parse)(input).map_err(|e| format!("day{:02}: {e}", day.number))?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    parsed.part1();
+    let part1 = start.elapsed();
+    let start = Instant::now();
+    parsed.part2();
+    let part2 = start.elapsed();
+    if let Some(samples) = samples {
+        samples[0].push(parse);
+        samples[1].push(part1);
+        samples[2].push(part2);
+    }
+    Ok(())
+}
+
+fn ns(ns: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns as u64))
+}
+
+/// Benchmarks `day` and compares it to its saved baseline.
+///
+/// Returns whether any step regressed past the threshold.
+pub fn bench(day: &Day, input: &str, opts: &BenchOptions) -> Result<bool, String> {
+    for _ in 0..opts.warmup {
+        measure(day, input, None)?;
+    }
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..opts.iterations {
+        measure(day, input, Some(&mut samples))?;
+    }
+    let [parse, part1, part2] = samples.map(|mut s| Stats::from_samples(&mut s));
+    let current = Baseline {
+        day: day.number,
+        iterations: opts.iterations,
+        parse,
+        part1,
+        part2,
+    };
+
+    let path = opts
+        .baseline
+        .clone()
+        .unwrap_or_else(|| default_baseline_path(day.number));
+    let baseline = load_baseline(&path)?;
+
+    println!(
+        "day{:02} ({} iterations, {} warm-up)",
+        day.number, opts.iterations, opts.warmup
+    );
+    println!(
+        "{:<6} {:>12} {:>12} {:>12} {:>10}",
+        "", "median", "min", "stddev", "baseline"
+    );
+    let mut regressed = false;
+    for (name, stats, base) in [
+        ("parse", &current.parse, baseline.as_ref().map(|b| &b.parse)),
+        ("part1", &current.part1, baseline.as_ref().map(|b| &b.part1)),
+        ("part2", &current.part2, baseline.as_ref().map(|b| &b.part2)),
+    ] {
+        let change = base
+            .filter(|b| b.median_ns > 0.0)
+            .map(|b| (stats.median_ns / b.median_ns - 1.0) * 100.0);
+        let flag = change.is_some_and(|c| c > opts.threshold);
+        regressed |= flag;
+        println!(
+            "{:<6} {:>12} {:>12} {:>12} {:>10}{}",
+            name,
+            ns(stats.median_ns),
+            ns(stats.min_ns),
+            ns(stats.stddev_ns),
+            change.map_or("-".to_owned(), |c| format!("{c:+.1}%")),
+            if flag { "  REGRESSION" } else { "" }
+        );
+    }
+
+    if opts.save_baseline {
+        save_baseline(&path, &current)?;
+        println!("baseline saved to {}", path.display());
+    }
+    Ok(regressed)
+}
+
+#[test]
+fn test_stats() {
+    let mut samples = [4, 1, 3, 2].map(Duration::from_nanos);
+    let stats = Stats::from_samples(&mut samples);
+    assert_eq!(stats.median_ns, 2.5);
+    assert_eq!(stats.min_ns, 1.0);
+    assert!((stats.stddev_ns - 1.25f64.sqrt()).abs() < 1e-9);
+}
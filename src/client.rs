@@ -0,0 +1,306 @@
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use advent_of_code_2023::solution::Answer;
+
+pub const YEAR: u16 = 2023;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/choumarin/advent_of_code_2023";
+
+/// Settings of `aoc.toml`, each overridden by its environment variable.
+#[derive(Deserialize, Default)]
+pub struct Config {
+    /// `AOC_SESSION`: value of the site's `session` cookie
+    pub session: Option<String>,
+    /// `AOC_BASE_URL`: where to reach the site, e.g. a local test server
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut config = if path.exists() {
+            let data = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+            toml::from_str(&data).map_err(|e| format!("{}: {e}", path.display()))?
+        } else {
+            Config::default()
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way
+    Wrong,
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part was already solved, so the answer was not checked
+    AlreadySolved,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait } => write!(f, "rate limited, retry in {wait:?}"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// `1m 23s` or `45s`, as in "You have 1m 23s left to wait".
+fn parse_wait(s: &str) -> Option<Duration> {
+    s.split_whitespace()
+        .try_fold(Duration::ZERO, |total, token| {
+            let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            let secs = match unit {
+                "h" => n * 3600,
+                "m" => n * 60,
+                "s" => n,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(secs))
+        })
+}
+
+/// Reads the outcome off the page returned by an answer submission.
+pub fn parse_outcome(page: &str) -> Result<Outcome, String> {
+    if page.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if page.contains("That's not the right answer") {
+        Ok(if page.contains("too high") {
+            Outcome::TooHigh
+        } else if page.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        })
+    } else if page.contains("You gave an answer too recently") {
+        let wait = page
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .unwrap_or(Duration::from_secs(60));
+        Ok(Outcome::RateLimited { wait })
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::AlreadySolved)
+    } else {
+        Err("could not understand the site's response".to_owned())
+    }
+}
+
+/// Talks to the Advent of Code site on behalf of the session's account.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let session = config.session.as_deref().ok_or_else(|| {
+            "no session token, set AOC_SESSION or `session` in aoc.toml".to_owned()
+        })?;
+        Ok(Client::new(
+            config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+            session,
+        ))
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/input", self.day_url(day));
+        ureq::get(&url)
+            .header("Cookie", &self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut r| r.body_mut().read_to_string())
+            .map_err(|e| match e {
+                ureq::Error::StatusCode(400) => format!("{url}: session token rejected"),
+                ureq::Error::StatusCode(404) => format!("{url}: day {day} is not unlocked yet"),
+                e => format!("{url}: {e}"),
+            })
+    }
+
+    /// Submits `answer` to `part` of `day`.
+    pub fn submit(&self, day: u8, part: u8, answer: Answer) -> Result<Outcome, String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let page = ureq::post(&url)
+            .header("Cookie", &self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .and_then(|mut r| r.body_mut().read_to_string())
+            .map_err(|e| format!("{url}: {e}"))?;
+        parse_outcome(&page).map_err(|e| format!("{url}: {e}"))
+    }
+}
+
+/// Where the input of `day` is embedded from.
+pub fn input_path(inputs_dir: &Path, day: u8) -> PathBuf {
+    inputs_dir.join(format!("day{day:02}")).join("input.txt")
+}
+
+pub fn default_inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("days")
+}
+
+/// Downloads the input of `day` into `inputs_dir`, unless it is already there.
+///
+/// Returns the input's path and whether it was downloaded.
+pub fn fetch(client: &Client, inputs_dir: &Path, day: u8) -> Result<(PathBuf, bool), String> {
+    let path = input_path(inputs_dir, day);
+    if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        return Ok((path, false));
+    }
+    let input = client.input(day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    }
+    fs::write(&path, input).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok((path, true))
+}
+
+/// A stand-in for the site, answering a single request.
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves `body` with `status` to the first request, then returns that request.
+    pub fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base_url, server)
+    }
+}
+
+#[test]
+fn test_fetch() {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let (base_url, server) = mock::serve_once("200 OK", "1 2 3\n");
+    let client = Client::new(&base_url, "secret");
+
+    let (path, downloaded) = fetch(&client, &dir, 9).unwrap();
+    assert!(downloaded);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2023/day/9/input "));
+    assert!(request.contains("session=secret"));
+
+    // The mock server is gone, so this only works from the cache
+    assert_eq!(fetch(&client, &dir, 9).unwrap(), (path, false));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_submit() {
+    let (base_url, server) = mock::serve_once(
+        "200 OK",
+        "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>",
+    );
+    let client = Client::new(&base_url, "secret");
+    assert_eq!(client.submit(7, 2, 244990514).unwrap(), Outcome::TooHigh);
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /2023/day/7/answer "));
+    assert!(request.ends_with("level=2&answer=244990514"));
+}
+
+#[test]
+fn test_parse_outcome() {
+    assert_eq!(
+        parse_outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.").unwrap(),
+        Outcome::RateLimited { wait: Duration::from_secs(83) }
+    );
+    assert_eq!(
+        parse_outcome("That's the right answer! You are one gold star closer").unwrap(),
+        Outcome::Correct
+    );
+    assert_eq!(
+        parse_outcome("That's not the right answer. If you're stuck").unwrap(),
+        Outcome::Wrong
+    );
+    assert!(parse_outcome("<html></html>").is_err());
+}
+
+#[test]
+fn test_fetch_locked_day() {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-locked-{}", std::process::id()));
+    let (base_url, server) = mock::serve_once("404 Not Found", "");
+    let client = Client::new(&base_url, "secret");
+    assert!(fetch(&client, &dir, 25)
+        .unwrap_err()
+        .contains("not unlocked yet"));
+    server.join().unwrap();
+    assert!(!dir.exists());
+}
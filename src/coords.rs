@@ -0,0 +1,244 @@
+use num::abs;
+use strum_macros::EnumIter;
+
+/// A position on a grid, or a move between two. Ordered line by line, then column by column.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Coords {
+    pub line: i64,
+    pub col: i64,
+}
+
+impl std::ops::Add for Coords {
+    type Output = Coords;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            line: self.line + rhs.line,
+            col: self.col + rhs.col,
+        }
+    }
+}
+
+impl std::ops::AddAssign for Coords {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub for Coords {
+    type Output = Coords;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl std::ops::Neg for Coords {
+    type Output = Coords;
+
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            line: -self.line,
+            col: -self.col,
+        }
+    }
+}
+
+impl std::ops::Mul<i64> for Coords {
+    type Output = Coords;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::Output {
+            line: self.line * rhs,
+            col: self.col * rhs,
+        }
+    }
+}
+
+/// From `(line, col)` indices.
+impl From<(usize, usize)> for Coords {
+    fn from((line, col): (usize, usize)) -> Self {
+        Coords {
+            line: line as i64,
+            col: col as i64,
+        }
+    }
+}
+
+/// To `(line, col)` indices, failing for negative coordinates.
+impl TryFrom<Coords> for (usize, usize) {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(coords: Coords) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(coords.line)?, usize::try_from(coords.col)?))
+    }
+}
+
+impl Coords {
+    pub fn dist_square(&self, other: &Coords) -> i64 {
+        abs(other.line - self.line) + abs(other.col - self.col)
+    }
+}
+
+/// Which way to go at a fork, or to turn.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+/// One of the 4 directions on a grid, lines going down.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, EnumIter)]
+pub enum Direction4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Direction4 {
+    pub fn offset(self) -> Coords {
+        match self {
+            Direction4::N => Coords { line: -1, col: 0 },
+            Direction4::E => Coords { line: 0, col: 1 },
+            Direction4::S => Coords { line: 1, col: 0 },
+            Direction4::W => Coords { line: 0, col: -1 },
+        }
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction4::N => Direction4::W,
+            Direction4::E => Direction4::N,
+            Direction4::S => Direction4::E,
+            Direction4::W => Direction4::S,
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_left().turn_left()
+    }
+}
+
+/// One of the 8 directions on a grid, diagonals included, lines going down.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, EnumIter)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    const CLOCKWISE: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    pub fn offset(self) -> Coords {
+        let (line, col) = match self {
+            Direction8::N => (-1, 0),
+            Direction8::NE => (-1, 1),
+            Direction8::E => (0, 1),
+            Direction8::SE => (1, 1),
+            Direction8::S => (1, 0),
+            Direction8::SW => (1, -1),
+            Direction8::W => (0, -1),
+            Direction8::NW => (-1, -1),
+        };
+        Coords { line, col }
+    }
+
+    fn rotated(self, eighths: usize) -> Self {
+        Self::CLOCKWISE[(self as usize + eighths) % 8]
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotated(7)
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotated(1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotated(4)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::N => Direction8::N,
+            Direction4::E => Direction8::E,
+            Direction4::S => Direction8::S,
+            Direction4::W => Direction8::W,
+        }
+    }
+}
+
+#[test]
+fn test_coords_ops() {
+    let a = Coords { line: 1, col: -2 };
+    let mut b = Coords::from((3, 4));
+    assert_eq!(b - a, Coords { line: 2, col: 6 });
+    assert_eq!(-a * 3, Coords { line: -3, col: 6 });
+    b += a;
+    assert_eq!(<(usize, usize)>::try_from(b), Ok((4, 2)));
+    assert!(<(usize, usize)>::try_from(a).is_err());
+    assert!(a < b && Coords { line: 1, col: 5 } < b);
+}
+
+#[test]
+fn test_directions() {
+    use strum::IntoEnumIterator;
+
+    assert_eq!(Direction4::N.turn_right(), Direction4::E);
+    assert_eq!(Direction4::W.turn(Turn::Left), Direction4::S);
+    assert!(Direction4::iter().all(|d| d.offset() == -d.opposite().offset()));
+    assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+    assert_eq!(Direction8::SE.opposite(), Direction8::NW);
+    assert!(Direction8::iter()
+        .all(|d| d.offset() == -d.opposite().offset() && d.turn_left().turn_right() == d));
+    assert_eq!(
+        Direction8::from(Direction4::W).offset(),
+        Direction4::W.offset()
+    );
+}
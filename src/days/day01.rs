@@ -1,15 +1,68 @@
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!("day01/input.txt");
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
 
-fn part1(input: &str) -> u32 {
+pub const INPUT: &str = include_str!("day01/input.txt");
+
+const DIGIT_NAMES: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters, digits and spelled out digits, each with at least one digit.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.range(1..=6);
+        let digit_at = rng.range(0..=len - 1);
+        for i in 0..len {
+            if i == digit_at || rng.chance(0.2) {
+                input.push(char::from(b'1' + rng.below(9) as u8));
+            } else if rng.chance(0.3) {
+                let name = rng.pick(DIGIT_NAMES);
+                input.push_str(name);
+            } else {
+                for _ in 0..rng.range(1..=3) {
+                    input.push(char::from(b'a' + rng.below(26) as u8));
+                }
+            }
+        }
+        input.push('\n');
+    }
     input
-        .lines()
-        .map(|l| {
-            first_num(l.to_owned(), false, false).unwrap() * 10
-                + first_num(l.to_owned(), false, true).unwrap()
-        })
-        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Answer {
+        lines
+            .iter()
+            .map(|l| {
+                first_num(l.to_owned(), false, false).unwrap() * 10
+                    + first_num(l.to_owned(), false, true).unwrap()
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Parsed) -> Answer {
+        lines
+            .iter()
+            .map(|l| {
+                first_num(l.to_owned(), true, false).unwrap() * 10
+                    + first_num(l.to_owned(), true, true).unwrap()
+            })
+            .sum::<u32>()
+            .into()
+    }
 }
 
 fn first_num(mut s: String, with_letters: bool, reverse: bool) -> Option<u32> {
@@ -82,45 +135,3 @@ fn test_first_num() {
     );
     assert_eq!(first_num(String::from("goiydabsyfvuse"), true, true), None);
 }
-
-fn part2(input: &str) -> u32 {
-    input
-        .lines()
-        .map(|l| {
-            first_num(l.to_owned(), true, false).unwrap() * 10
-                + first_num(l.to_owned(), true, true).unwrap()
-        })
-        .sum()
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[test]
-    fn test_parse1() {
-        let test_input_1 = "1abc2
-        pqr3stu8vwx
-        a1b2c3d4e5f
-        treb7uchet";
-
-        assert_eq!(part1(test_input_1), 142);
-    }
-
-    #[test]
-    fn test_parse2() {
-        let test_input_2 = "two1nine
-        eightwothree
-        abcone2threexyz
-        xtwone3four
-        4nineeightseven2
-        zoneight234
-        7pqrstsixteen";
-
-        assert_eq!(part2(test_input_2), 281);
-    }
-}
-
-fn main() {
-    println!("part1 {}", part1(INPUT));
-    println!("part2 {}", part2(INPUT));
-}
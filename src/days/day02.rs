@@ -0,0 +1,223 @@
+use std::{collections::HashMap, str::FromStr};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::parse::{parse_lines, parse_token, ParseError};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day02/input.txt");
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, EnumIter)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FromStr for Color {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(ParseError::at(s, s, "`red`, `green` or `blue`")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Hand {
+    cubes: HashMap<Color, u32>,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Hand {
+            cubes: s
+                .split(',')
+                .map(|cube| {
+                    let mut v = cube.split_whitespace();
+                    let count = v
+                        .next()
+                        .ok_or_else(|| ParseError::after(s, cube, "a cube count"))?;
+                    let color = v
+                        .next()
+                        .ok_or_else(|| ParseError::after(s, count, "a color"))?;
+                    Ok((
+                        Color::from_str(color).map_err(|e| e.within(s, color))?,
+                        parse_token(s, count, "a cube count")?,
+                    ))
+                })
+                .collect::<Result<HashMap<Color, u32>, ParseError>>()?,
+        })
+    }
+}
+
+impl Hand {
+    fn power(&self) -> u32 {
+        let mut res = 1;
+        for c in Color::iter() {
+            res *= self.cubes.get(&c).unwrap_or(&0);
+        }
+        res
+    }
+}
+
+#[test]
+fn test_parse_hand() {
+    let s = "7 blue, 4 red, 11 green";
+    let h = Hand::from_str(s).unwrap();
+    assert_eq!(
+        h.cubes,
+        HashMap::from([(Color::Blue, 7), (Color::Red, 4), (Color::Green, 11)])
+    );
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    id: u32,
+    hands: Vec<Hand>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, hands) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::after(s, s, "`:`"))?;
+        let mut game = header.split_whitespace();
+        match game.next() {
+            Some("Game") => {}
+            Some(t) => return Err(ParseError::at(s, t, "`Game`")),
+            None => return Err(ParseError::at(s, header, "`Game`")),
+        }
+        let id = game
+            .next()
+            .ok_or_else(|| ParseError::after(s, header, "a game id"))?;
+        let id = parse_token(s, id, "a game id")?;
+        let hands = hands
+            .split(';')
+            .map(|h| Hand::from_str(h).map_err(|e| e.within(s, h)))
+            .collect::<Result<Vec<Hand>, ParseError>>()?;
+        Ok(Game { id, hands })
+    }
+}
+
+impl Game {
+    fn is_possible(&self, initial: &HashMap<Color, u32>) -> bool {
+        for hand in self.hands.iter() {
+            for (&color, &count) in hand.cubes.iter() {
+                if &count > initial.get(&color).unwrap() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn min_initial(&self) -> Hand {
+        let mut res = Hand {
+            cubes: HashMap::new(),
+        };
+        for hand in self.hands.iter() {
+            for (&color, &count) in hand.cubes.iter() {
+                res.cubes
+                    .entry(color)
+                    .and_modify(|e| *e = std::cmp::max(*e, count))
+                    .or_insert(count);
+            }
+        }
+        res
+    }
+}
+
+#[test]
+fn test_parse_game() {
+    let s = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+    let g = Game::from_str(s).unwrap();
+    assert_eq!(
+        g,
+        Game {
+            id: 1,
+            hands: vec![
+                Hand {
+                    cubes: HashMap::from([(Color::Blue, 3), (Color::Red, 4)])
+                },
+                Hand {
+                    cubes: HashMap::from([(Color::Red, 1), (Color::Green, 2), (Color::Blue, 6)])
+                },
+                Hand {
+                    cubes: HashMap::from([(Color::Green, 2)])
+                },
+            ]
+        }
+    )
+}
+
+#[test]
+fn test_parse_game_error() {
+    let err = Game::from_str("Game 1: 3 blue, 4 red; 1 purple").unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (26, "purple"));
+    let err = Game::from_str("Game x: 3 blue").unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (6, "x"));
+    let err = Game::from_str("Game 1: 3 blue, 4").unwrap_err();
+    assert_eq!((err.column, err.expected.as_str()), (18, "a color"));
+}
+
+/// `size` games of 1 to 6 draws of up to 20 cubes of each color.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let draws = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let count = rng.range(1..=3) as usize;
+                colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        input += &format!("Game {id}: {draws}\n");
+    }
+    input
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(games: &Self::Parsed) -> Answer {
+        let initial: HashMap<Color, u32> =
+            HashMap::from([(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)]);
+        games
+            .iter()
+            .filter(|g| g.is_possible(&initial))
+            .map(|g| g.id)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(games: &Self::Parsed) -> Answer {
+        games
+            .iter()
+            .map(|g| g.min_initial())
+            .map(|h| h.power())
+            .sum::<u32>()
+            .into()
+    }
+}
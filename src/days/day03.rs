@@ -0,0 +1,248 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::coords::Coords;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day03/input.txt");
+
+#[derive(Default, PartialEq, Eq, Debug, Hash)]
+pub struct PartNum {
+    val: i32,
+    line: i32,
+    col: i32,
+    len: i32,
+}
+
+impl PartNum {
+    fn adj_chars(&self, text: &str) -> Vec<(Coords, char)> {
+        let mut adj = Vec::new();
+        if self.line > 0 {
+            let line = text.lines().nth((self.line - 1) as usize).unwrap();
+            adj.extend(
+                line.chars()
+                    .enumerate()
+                    .filter(|(i, _)| *i as i32 >= self.col - 1 && *i as i32 <= self.col + self.len)
+                    .map(|(i, c)| {
+                        (
+                            Coords {
+                                line: (self.line - 1) as i64,
+                                col: i as i64,
+                            },
+                            c,
+                        )
+                    }),
+            );
+        }
+        let line = text.lines().nth((self.line) as usize).unwrap();
+        adj.extend(
+            line.chars()
+                .enumerate()
+                .filter(|(i, _)| *i as i32 == self.col - 1 || *i as i32 == self.col + self.len)
+                .map(|(i, c)| {
+                    (
+                        Coords {
+                            line: self.line as i64,
+                            col: i as i64,
+                        },
+                        c,
+                    )
+                }),
+        );
+        if let Some(line) = text.lines().nth((self.line + 1) as usize) {
+            adj.extend(
+                line.chars()
+                    .enumerate()
+                    .filter(|(i, _)| *i as i32 >= self.col - 1 && *i as i32 <= self.col + self.len)
+                    .map(|(i, c)| {
+                        (
+                            Coords {
+                                line: (self.line + 1) as i64,
+                                col: i as i64,
+                            },
+                            c,
+                        )
+                    }),
+            );
+        }
+        adj
+    }
+
+    fn has_adj_symbols(&self, text: &str) -> bool {
+        self.adj_chars(text)
+            .iter()
+            .any(|(_, c)| !c.is_ascii_digit() && *c != '.')
+    }
+
+    fn gears(&self, text: &str) -> Vec<Coords> {
+        self.adj_chars(text)
+            .iter()
+            .filter_map(|&(coords, c)| (c == '*').then_some(coords))
+            .collect()
+    }
+}
+
+#[test]
+fn test_adj_char() {
+    let text = "abc
+def
+ghi";
+    let p = PartNum {
+        col: 1,
+        len: 1,
+        line: 1,
+        val: 1,
+    };
+    assert_eq!(
+        p.adj_chars(text)
+            .iter()
+            .map(|(_, x)| *x)
+            .collect::<Vec<_>>(),
+        vec!['a', 'b', 'c', 'd', 'f', 'g', 'h', 'i']
+    );
+}
+
+fn parts_in_str(line: i32, text: &str) -> Vec<PartNum> {
+    let mut parts = Vec::new();
+    let mut op: Option<PartNum> = None;
+    for (i, c) in text.chars().enumerate() {
+        if c.is_ascii_digit() {
+            if let Some(ref mut p) = op {
+                p.len += 1;
+                p.val *= 10;
+                p.val += c.to_digit(10).unwrap() as i32;
+            } else {
+                op = Some(PartNum {
+                    line,
+                    val: c.to_digit(10).unwrap() as i32,
+                    col: i as i32,
+                    len: 1,
+                });
+            }
+        } else if let Some(p) = op {
+            parts.push(p);
+            op = None;
+        }
+    }
+    if let Some(p) = op {
+        parts.push(p);
+    }
+    parts
+}
+
+#[test]
+fn test_parts_in_str() {
+    let text = "...305.12";
+    assert_eq!(
+        parts_in_str(0, text),
+        vec![
+            PartNum {
+                line: 0,
+                val: 305,
+                col: 3,
+                len: 3
+            },
+            PartNum {
+                line: 0,
+                val: 12,
+                col: 7,
+                len: 2
+            }
+        ]
+    );
+}
+
+pub struct Schematic {
+    text: String,
+    parts: Vec<PartNum>,
+}
+
+const SYMBOLS: &[char] = &['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// How many numbers of `grid` touch the tile at `line`, `col`.
+fn numbers_around(grid: &[Vec<char>], line: usize, col: usize) -> usize {
+    grid[line.saturating_sub(1)..=(line + 1).min(grid.len() - 1)]
+        .iter()
+        .map(|row| {
+            row[col.saturating_sub(1)..=(col + 1).min(row.len() - 1)]
+                .split(|tile| !tile.is_ascii_digit())
+                .filter(|digits| !digits.is_empty())
+                .count()
+        })
+        .sum()
+}
+
+/// A `size` by `size` schematic. A `*` touches at most two numbers, so gear ratios fit.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut grid = vec![vec!['.'; size]; size];
+    for line in grid.iter_mut() {
+        let mut col = rng.index(4);
+        loop {
+            let len = rng.range(1..=3) as usize;
+            if col + len > size {
+                break;
+            }
+            for (i, tile) in line[col..col + len].iter_mut().enumerate() {
+                *tile = char::from(b'0' + rng.range(i64::from(i == 0)..=9) as u8);
+            }
+            col += len + 1 + rng.index(6);
+        }
+    }
+    for _ in 0..size * size / 10 {
+        let (line, col) = (rng.index(size), rng.index(size));
+        if grid[line][col] != '.' {
+            continue;
+        }
+        let mut symbol = *rng.pick(SYMBOLS);
+        if symbol == '*' && numbers_around(&grid, line, col) > 2 {
+            symbol = '#';
+        }
+        grid[line][col] = symbol;
+    }
+    grid.into_iter()
+        .map(|line| line.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(Schematic {
+            text: input.to_owned(),
+            parts: input
+                .lines()
+                .enumerate()
+                .flat_map(|(i, l)| parts_in_str(i as i32, l))
+                .collect(),
+        })
+    }
+
+    fn part1(schematic: &Self::Parsed) -> Answer {
+        schematic
+            .parts
+            .iter()
+            .filter(|p| p.has_adj_symbols(&schematic.text))
+            .map(|p| p.val)
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(schematic: &Self::Parsed) -> Answer {
+        let mut gears: HashMap<Coords, HashSet<&PartNum>> = HashMap::new();
+        for part in schematic.parts.iter() {
+            for gear in part.gears(&schematic.text) {
+                gears.entry(gear).or_default().insert(part);
+            }
+        }
+        gears
+            .iter()
+            .filter(|(_, parts)| parts.len() >= 2)
+            .map(|(_, parts)| parts.iter().map(|p| p.val).product::<i32>())
+            .sum::<i32>()
+            .into()
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    str::FromStr,
+};
+
+use crate::parse::{parse_lines, parse_token, ParseError};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day04/input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Card {
+    id: i32,
+    have: HashSet<i32>,
+    win: HashSet<i32>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (card, win) = s
+            .split_once('|')
+            .ok_or_else(|| ParseError::after(s, s, "`|`"))?;
+        let (header, have) = card
+            .split_once(':')
+            .ok_or_else(|| ParseError::after(s, card, "`:`"))?;
+        let mut header = header.split_whitespace();
+        let card_word = match header.next() {
+            Some(t @ "Card") => t,
+            Some(t) => return Err(ParseError::at(s, t, "`Card`")),
+            None => return Err(ParseError::at(s, card, "`Card`")),
+        };
+        let id = header
+            .next()
+            .ok_or_else(|| ParseError::after(s, card_word, "a card id"))?;
+        let numbers = |list: &str| {
+            list.split_whitespace()
+                .map(|i| parse_token(s, i, "a number"))
+                .collect::<Result<_, _>>()
+        };
+        Ok(Card {
+            id: parse_token(s, id, "a card id")?,
+            have: numbers(have)?,
+            win: numbers(win)?,
+        })
+    }
+}
+
+impl Card {
+    fn matching(&self) -> i32 {
+        let mut p: i32 = 0;
+        for &h in self.have.iter() {
+            if self.win.contains(&h) {
+                p += 1;
+            }
+        }
+        p
+    }
+
+    fn points(&self) -> i32 {
+        let mut p: i32 = 0;
+        for &h in self.have.iter() {
+            if self.win.contains(&h) {
+                if p == 0 {
+                    p = 1;
+                } else {
+                    p *= 2;
+                }
+            }
+        }
+        p
+    }
+}
+
+#[test]
+fn test_parse_card() {
+    let s = "Card 1: 41 48 17 | 83 86";
+    assert_eq!(
+        Card::from_str(s),
+        Ok(Card {
+            id: 1,
+            have: HashSet::from([41, 48, 17]),
+            win: HashSet::from([83, 86]),
+        })
+    )
+}
+
+#[test]
+fn test_parse_card_error() {
+    let err = Card::from_str("Card 1: 41 4x | 83 86").unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (12, "4x"));
+    let err = Card::from_str("Card: 41 48 | 83 86").unwrap_err();
+    assert_eq!((err.column, err.expected.as_str()), (5, "a card id"));
+    let err = Card::from_str("Card 1: 41 48").unwrap_err();
+    assert_eq!(err.expected, "`|`");
+}
+
+#[test]
+fn test_points() {
+    let s = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+    assert_eq!(Card::from_str(s).unwrap().points(), 8)
+}
+
+/// `size` cards of 8 numbers held and 5 winning ones.
+///
+/// Most cards match nothing, so the number of copies won stays about linear in `size`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let mut numbers = (1..100).collect::<Vec<i32>>();
+        rng.shuffle(&mut numbers);
+        let matching = if rng.chance(0.6) {
+            0
+        } else {
+            (rng.range(1..=3) as usize).min(size - id)
+        };
+        let win = &numbers[..5];
+        let mut have = numbers[..matching].to_vec();
+        have.extend(&numbers[5..13 - matching]);
+        rng.shuffle(&mut have);
+        let list = |numbers: &[i32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        input += &format!("Card {id:>3}: {} | {}\n", list(&have), list(win));
+    }
+    input
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(cards: &Self::Parsed) -> Answer {
+        cards.iter().map(|c| c.points()).sum::<i32>().into()
+    }
+
+    fn part2(cards: &Self::Parsed) -> Answer {
+        let mut all_cards = cards
+            .iter()
+            .map(|c| (c.id, (c, 1)))
+            .collect::<BTreeMap<_, _>>();
+        for id in all_cards.keys().copied().collect::<Vec<_>>() {
+            let matching = all_cards.get(&id).unwrap().0.matching();
+            if matching == 0 {
+                continue;
+            }
+            let copies = all_cards.get(&id).unwrap().1;
+            // Copies past the last card are never won.
+            for (_, (_, won)) in all_cards.range_mut((id + 1)..(id + matching + 1)) {
+                *won += copies;
+            }
+        }
+        for (id, (_, copies)) in &all_cards {
+            crate::trace!("card", id = id, copies = copies);
+        }
+        all_cards.iter().map(|(_, (_, i))| i).sum::<i32>().into()
+    }
+}
+
+#[test]
+fn test_matches_past_last_card() {
+    let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 3\n").unwrap();
+    assert_eq!(Day04::part2(&cards), 3);
+}
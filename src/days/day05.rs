@@ -0,0 +1,302 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::algo::intersect;
+use crate::parse::{parse_token, ParseError};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day05/input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MapLine {
+    source_start: i64,
+    dest_start: i64,
+    len: i64,
+}
+
+type Map = Vec<MapLine>;
+
+impl FromStr for MapLine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let mut next = |expected| {
+            let token = parts
+                .next()
+                .ok_or_else(|| ParseError::after(s, s.trim_end(), expected))?;
+            parse_token(s, token, expected)
+        };
+        Ok(MapLine {
+            dest_start: next("a destination start")?,
+            source_start: next("a source start")?,
+            len: next("a range length")?,
+        })
+    }
+}
+
+#[test]
+fn test_map_parse() {
+    assert_eq!(
+        "50 98 2".parse(),
+        Ok(MapLine {
+            source_start: 98,
+            dest_start: 50,
+            len: 2
+        })
+    );
+    let err = parse_input("seeds: 79 14\n\nseed-to-soil map:\n50 98").unwrap_err();
+    assert_eq!((err.line, err.column), (4, 6));
+    let err = parse_input("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 16: expected a range length for the last seed, found nothing"
+    );
+}
+
+impl MapLine {
+    fn source_range(&self) -> RangeInclusive<i64> {
+        self.source_start..=(self.source_start + self.len - 1)
+    }
+    fn offset(&self) -> i64 {
+        self.dest_start - self.source_start
+    }
+}
+
+type SeedsRange = RangeInclusive<i64>;
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    fn seed_ranges(&self, part2: bool) -> Vec<SeedsRange> {
+        if !part2 {
+            self.seeds.iter().map(|&i| i..=i).collect()
+        } else {
+            self.seeds
+                .chunks_exact(2)
+                .map(|r| r[0]..=r[0] + r[1] - 1)
+                .collect()
+        }
+    }
+
+    fn lowest_location(&self, part2: bool) -> i64 {
+        let mut seeds = self.seed_ranges(part2);
+        for (i, m) in self.maps.iter().enumerate() {
+            let mut next_seeds = Vec::new();
+            let ranges_in = seeds.len();
+            for s in seeds.drain(..) {
+                next_seeds.append(&mut apply_map(s, m));
+            }
+            crate::debug!(
+                "map",
+                index = i + 1,
+                lines = m.len(),
+                ranges_in = ranges_in,
+                ranges_out = next_seeds.len()
+            );
+            seeds = next_seeds;
+        }
+        seeds.into_iter().map(|s| *s.start()).min().unwrap()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+    // Assume maps are in the proper order
+    let mut lines = input.lines();
+    let seed_line = lines.next().unwrap_or_default();
+    let seeds = match seed_line.split_once(':') {
+        Some(("seeds", seeds)) => seeds,
+        _ => return Err(ParseError::at(seed_line, seed_line, "`seeds:`").on_line(1)),
+    };
+    let tokens = seeds.split_whitespace().collect::<Vec<_>>();
+    let seeds = tokens
+        .iter()
+        .map(|i| parse_token(seed_line, i, "a seed").map_err(|e| e.on_line(1)))
+        .collect::<Result<Vec<i64>, _>>()?;
+    // Part 2 reads the seeds as pairs of a start and a length
+    match tokens.last() {
+        None => return Err(ParseError::after(seed_line, seed_line, "a seed").on_line(1)),
+        Some(last) if tokens.len() % 2 == 1 => {
+            return Err(
+                ParseError::after(seed_line, last, "a range length for the last seed").on_line(1),
+            )
+        }
+        _ => {}
+    }
+    let mut maps = Vec::new();
+    let mut current_map: Option<Vec<MapLine>> = None;
+    for (i, line) in lines.enumerate() {
+        if line.ends_with(':') {
+            if let Some(map) = current_map {
+                maps.push(map);
+                current_map = None;
+            }
+        } else if line.is_empty() {
+            continue;
+        } else {
+            current_map
+                .get_or_insert(Vec::new())
+                .push(line.parse().map_err(|e: ParseError| e.on_line(i + 2))?);
+        }
+    }
+    if let Some(map) = current_map {
+        maps.push(map);
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+fn offset_range(range: SeedsRange, offset: i64) -> SeedsRange {
+    SeedsRange::new(range.start() + offset, range.end() + offset)
+}
+
+fn apply_map(source: SeedsRange, map: &Map) -> Vec<SeedsRange> {
+    let mut ret = Vec::new();
+    let mut remaining_seeds = vec![source];
+    for m in map.iter() {
+        let current_seeds = remaining_seeds.clone();
+        remaining_seeds.clear();
+        for s in current_seeds {
+            if let Some(intersection) = intersect(&s, &m.source_range()) {
+                ret.push(offset_range(intersection.clone(), m.offset()));
+                let exclusions = vec![
+                    *s.start()..=(intersection.start() - 1),
+                    (*intersection.end() + 1)..=*s.end(),
+                ];
+                for e in exclusions {
+                    if !e.is_empty() {
+                        remaining_seeds.push(e);
+                    }
+                }
+            } else {
+                remaining_seeds.push(s);
+            }
+        }
+    }
+    ret.append(&mut remaining_seeds);
+
+    // TODO: Merge back ?
+
+    ret
+}
+
+#[test]
+fn test_apply_map() {
+    let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48";
+    let almanac = parse_input(input).unwrap();
+    let maps = &almanac.maps;
+    let seeds = almanac.seed_ranges(false);
+    assert_eq!(apply_map(seeds[0].clone(), &maps[0]), vec![81..=81]);
+    assert_eq!(apply_map(seeds[1].clone(), &maps[0]), vec![14..=14]);
+    assert_eq!(apply_map(seeds[2].clone(), &maps[0]), vec![57..=57]);
+    assert_eq!(apply_map(seeds[3].clone(), &maps[0]), vec![13..=13]);
+    let seeds = almanac.seed_ranges(true);
+    assert_eq!(apply_map(seeds[0].clone(), &maps[0]), vec![81..=94]);
+    assert_eq!(apply_map(seeds[1].clone(), &maps[0]), vec![57..=69]);
+}
+
+const MAP_NAMES: &[&str] = &[
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// An almanac of `size` seed ranges and seven maps of up to `size` ranges.
+///
+/// Numbers stay below max(`size`³, 64), capped at 2^32, so that the seeds can also be mapped
+/// one by one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let limit = (size as i64).pow(3).clamp(64, 1 << 32);
+    let seeds = (0..size.max(1))
+        .map(|_| {
+            let start = rng.range(0..=limit - 1);
+            let len = rng.range(1..=(limit - start).min(limit / size.max(1) as i64));
+            format!("{start} {len}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut input = format!("seeds: {seeds}\n");
+    for name in MAP_NAMES {
+        input += &format!("\n{name} map:\n");
+        let mut bounds = (0..size * 2)
+            .map(|_| rng.range(0..=limit))
+            .collect::<Vec<_>>();
+        bounds.sort_unstable();
+        bounds.dedup();
+        for range in bounds.chunks_exact(2) {
+            let len = range[1] - range[0];
+            let dest = rng.range(0..=limit - len);
+            input += &format!("{dest} {} {len}\n", range[0]);
+        }
+    }
+    input
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Answer {
+        almanac.lowest_location(false)
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Answer {
+        almanac.lowest_location(true)
+    }
+}
+
+/// Maps every seed on its own, through the first matching line of each map.
+pub struct Day05Reference;
+
+impl Almanac {
+    fn location(&self, seed: i64) -> i64 {
+        self.maps.iter().fold(seed, |n, map| {
+            map.iter()
+                .find(|m| m.source_range().contains(&n))
+                .map_or(n, |m| n + m.offset())
+        })
+    }
+}
+
+impl Solution for Day05Reference {
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Answer {
+        almanac
+            .seeds
+            .iter()
+            .map(|&s| almanac.location(s))
+            .min()
+            .unwrap()
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Answer {
+        almanac
+            .seed_ranges(true)
+            .into_iter()
+            .flatten()
+            .map(|s| almanac.location(s))
+            .min()
+            .unwrap()
+    }
+}
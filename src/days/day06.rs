@@ -0,0 +1,235 @@
+use crate::math::quadratic_roots;
+use crate::parse::{parse_token, ParseError};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day06/input.txt");
+
+#[derive(Debug)]
+pub struct Race {
+    time: i64,
+    distance: i64,
+}
+
+/// The numbers of a row, with the tokens they were read from.
+fn parse_row<'a>(line: &'a str, header: &str) -> Result<Vec<(&'a str, i64)>, ParseError> {
+    match line.split_once(':') {
+        Some((h, values)) if h == header => {
+            let row = values
+                .split_whitespace()
+                .map(|s| Ok((s, parse_token(line, s, "a number")?)))
+                .collect::<Result<Vec<_>, _>>()?;
+            if row.is_empty() {
+                return Err(ParseError::after(line, line, "a number"));
+            }
+            Ok(row)
+        }
+        _ => Err(ParseError::at(line, line, format!("`{header}:`"))),
+    }
+}
+
+/// The numbers of a row read as one, ignoring the spaces between them.
+fn joined(line: &str, row: &[(&str, i64)]) -> Result<i64, ParseError> {
+    let digits = row.iter().map(|(token, _)| *token).collect::<String>();
+    digits.parse().map_err(|_| {
+        let offset = |token: &str| token.as_ptr() as usize - line.as_ptr() as usize;
+        let (first, last) = (row[0].0, row[row.len() - 1].0);
+        let numbers = &line[offset(first)..offset(last) + last.len()];
+        ParseError::at(line, numbers, "numbers that fit in an `i64` once joined")
+    })
+}
+
+/// The races of part 1, and the single race of part 2 their numbers make.
+fn parse(input: &str) -> Result<(Vec<Race>, Race), ParseError> {
+    let mut lines = input.lines();
+    let time_line = lines.next().unwrap_or_default();
+    let distance_line = lines.next().unwrap_or_default();
+    let times = parse_row(time_line, "Time").map_err(|e| e.on_line(1))?;
+    let dists = parse_row(distance_line, "Distance").map_err(|e| e.on_line(2))?;
+    if let Some((token, _)) = times.get(dists.len()) {
+        return Err(ParseError::at(time_line, token, "a time with a distance").on_line(1));
+    }
+    if let Some((token, _)) = dists.get(times.len()) {
+        return Err(ParseError::at(distance_line, token, "a distance with a time").on_line(2));
+    }
+    let single = Race {
+        time: joined(time_line, &times).map_err(|e| e.on_line(1))?,
+        distance: joined(distance_line, &dists).map_err(|e| e.on_line(2))?,
+    };
+    let races = times
+        .into_iter()
+        .zip(dists)
+        .map(|((_, t), (_, d))| Race {
+            time: t,
+            distance: d,
+        })
+        .collect();
+    Ok((races, single))
+}
+
+impl Race {
+    /// Holding times that match the record, if any can.
+    fn roots(&self) -> Option<(f64, f64)> {
+        quadratic_roots(1.0, -self.time as f64, self.distance as f64)
+    }
+
+    fn possible_wins(&self) -> i64 {
+        let Some((a, b)) = self.roots() else {
+            // The record can't even be matched
+            return 0;
+        };
+        let b = if b.floor() == b {
+            (b - 1.0) as i64
+        } else {
+            b.floor() as i64
+        };
+        let a = if a.ceil() == a {
+            (a + 1.0) as i64
+        } else {
+            a.ceil() as i64
+        };
+        (b - a + 1).max(0)
+    }
+
+    fn possible_wins_by_trying(&self) -> i64 {
+        (0..=self.time)
+            .filter(|hold| hold * (self.time - hold) > self.distance)
+            .count() as i64
+    }
+}
+
+// dist(tb, tt) = (tt - tb) * tb - m = -tb^2 + tt*tb - m
+
+// tb = (tt +/- sqrt(tt^2 - 4*m)) / 2
+
+#[test]
+fn test_roots() {
+    assert_eq!(
+        Race {
+            time: 7,
+            distance: 9
+        }
+        .possible_wins(),
+        4
+    );
+    assert_eq!(
+        Race {
+            time: 15,
+            distance: 40
+        }
+        .possible_wins(),
+        8
+    );
+    assert_eq!(
+        Race {
+            time: 30,
+            distance: 200
+        }
+        .possible_wins(),
+        9
+    );
+    // The record can only be matched
+    assert_eq!(
+        Race {
+            time: 4,
+            distance: 4
+        }
+        .possible_wins(),
+        0
+    );
+}
+
+#[test]
+fn test_parse_unmatched() {
+    let err = parse("Time: 4 5\nDistance: 3\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 9: expected a time with a distance, found `5`"
+    );
+    let err = parse("Time: 4\nDistance: 3 2 1\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (2, 13, "2"));
+}
+
+#[test]
+fn test_parse_part2_race() {
+    let (races, race) = parse("Time:  7 15\nDistance: 9 40\n").unwrap();
+    assert_eq!((races.len(), race.time, race.distance), (2, 715, 940));
+    let err = parse("Time:\nDistance:\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 6: expected a number, found nothing"
+    );
+    let err = parse("Time: 4000000000 4000000000\nDistance: 1 1\n").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 7));
+    assert_eq!(err.token, "4000000000 4000000000");
+}
+
+#[test]
+fn test_unbeatable_record() {
+    let race = Race {
+        time: 4,
+        distance: 5,
+    };
+    assert_eq!(race.possible_wins(), 0);
+    assert_eq!(race.possible_wins_by_trying(), 0);
+    let races = parse("Time: 4\nDistance: 5\n").unwrap();
+    assert_eq!(Day06::part1(&races), 0);
+    assert_eq!(Day06::part2(&races), 0);
+}
+
+/// `size` races, at most 4 so that the single race of part 2 still fits in an `i64`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let best = |time: i64| (time / 2) * (time - time / 2);
+    loop {
+        let races = (0..size.clamp(1, 4))
+            .map(|_| {
+                let time = rng.range(7..=99);
+                (time, rng.range(best(time) / 2..=best(time) - 1))
+            })
+            .collect::<Vec<_>>();
+        let row = |values: Vec<i64>| values.iter().map(|v| format!("{v:>5}")).collect::<String>();
+        let times = row(races.iter().map(|r| r.0).collect());
+        let distances = row(races.iter().map(|r| r.1).collect());
+        let joined = |row: &str| row.replace(' ', "").parse::<i64>().unwrap();
+        if joined(&distances) < best(joined(&times)) {
+            return format!("Time:    {times}\nDistance:{distances}\n");
+        }
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = (Vec<Race>, Race);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1((races, _): &Self::Parsed) -> Answer {
+        races.iter().map(|r| r.possible_wins()).product()
+    }
+
+    fn part2((_, race): &Self::Parsed) -> Answer {
+        race.possible_wins()
+    }
+}
+
+/// Tries every hold time instead of solving the quadratic.
+pub struct Day06Reference;
+
+impl Solution for Day06Reference {
+    type Parsed = (Vec<Race>, Race);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1((races, _): &Self::Parsed) -> Answer {
+        races.iter().map(|r| r.possible_wins_by_trying()).product()
+    }
+
+    fn part2((_, race): &Self::Parsed) -> Answer {
+        race.possible_wins_by_trying()
+    }
+}
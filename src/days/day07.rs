@@ -1,7 +1,14 @@
 use core::fmt;
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
-const INPUT: &str = include_str!("day07/input.txt");
+use crate::parse::{parse_token, ParseError};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day07/input.txt");
 
 const CARDS_FACES_P1: &[char] = &[
     '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
@@ -28,13 +35,14 @@ fn test_type_ord() {
     assert!(HandType::FourKind > HandType::ThreeKind);
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum Rules {
     Part1,
     Part2,
 }
 
-struct ParsedHand {
+#[derive(Debug)]
+pub struct ParsedHand {
     cards: Vec<char>,
 }
 
@@ -46,25 +54,28 @@ struct Hand {
 }
 
 impl FromStr for ParsedHand {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((i, c)) = s.char_indices().find(|(_, c)| !CARDS_FACES_P1.contains(c)) {
+            return Err(ParseError::at(s, &s[i..i + c.len_utf8()], "a card"));
+        }
         let cards = s.chars().collect::<Vec<_>>();
-        if cards.len() != 5 || !cards.iter().all(|c| CARDS_FACES_P1.contains(c)) {
-            return Err(());
+        if cards.len() != 5 {
+            return Err(ParseError::at(s, s, "5 cards"));
         }
         Ok(ParsedHand { cards })
     }
 }
 
 impl ParsedHand {
-    fn hand(self, rules: Rules) -> Hand {
+    fn hand(&self, rules: Rules) -> Hand {
         Hand {
             hand_type: match rules {
                 Rules::Part1 => type_from_cards(&self.cards),
                 Rules::Part2 => type_from_cards_with_jocker(&self.cards),
             },
-            cards: self.cards,
+            cards: self.cards.clone(),
             rules,
         }
     }
@@ -178,6 +189,16 @@ fn type_from_cards_with_jocker(cards: &Vec<char>) -> HandType {
     }
 }
 
+#[test]
+fn test_parse_error() {
+    let err = parse_bet("32X3K 765").unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (3, "X"));
+    let err = parse_bet("32T3 765").unwrap_err();
+    assert_eq!(err.expected, "5 cards");
+    let err = parse_bet("32T3K").unwrap_err();
+    assert_eq!((err.column, err.expected.as_str()), (6, "a bet"));
+}
+
 #[test]
 fn test_parse() {
     assert_eq!(
@@ -285,23 +306,13 @@ fn test_cmp() {
     );
 }
 
-fn part1(input: &str) -> i64 {
-    let mut hands: Vec<(Hand, i64)> = input
-        .lines()
-        .map(|l| {
-            let mut parts = l.split_whitespace();
-            let hand = parts
-                .next()
-                .unwrap()
-                .parse::<ParsedHand>()
-                .unwrap()
-                .hand(Rules::Part1);
-            let bet = parts.next().unwrap().parse::<i64>().unwrap();
-            (hand, bet)
-        })
+fn total_winnings(bets: &[(ParsedHand, i64)], rules: Rules) -> i64 {
+    let mut hands: Vec<(Hand, i64)> = bets
+        .iter()
+        .map(|(hand, bet)| (hand.hand(rules), *bet))
         .collect::<Vec<_>>();
     hands.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
-    // dbg!(&hands);
+    crate::trace!("ranked", rules = rules, hands = hands);
     hands
         .into_iter()
         .enumerate()
@@ -309,61 +320,51 @@ fn part1(input: &str) -> i64 {
         .sum()
 }
 
-fn part2(input: &str) -> i64 {
-    let mut hands: Vec<(Hand, i64)> = input
-        .lines()
-        .map(|l| {
-            let mut parts = l.split_whitespace();
-            let hand = parts
-                .next()
-                .unwrap()
-                .parse::<ParsedHand>()
-                .unwrap()
-                .hand(Rules::Part2);
-            let bet = parts.next().unwrap().parse::<i64>().unwrap();
-            (hand, bet)
-        })
-        .collect::<Vec<_>>();
-    hands.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
-    // dbg!(&hands);
-    hands
-        .into_iter()
-        .enumerate()
-        .map(|(rank, (_, bet))| (rank + 1) as i64 * bet)
-        .sum()
+fn parse_bet(l: &str) -> Result<(ParsedHand, i64), ParseError> {
+    let mut parts = l.split_whitespace();
+    let hand = parts.next().ok_or_else(|| ParseError::at(l, l, "a hand"))?;
+    let bet = parts
+        .next()
+        .ok_or_else(|| ParseError::after(l, hand, "a bet"))?;
+    Ok((
+        hand.parse::<ParsedHand>().map_err(|e| e.within(l, hand))?,
+        parse_token(l, bet, "a bet")?,
+    ))
+}
+
+/// `size` distinct hands, at most 13^5, with bids up to 1000.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+    while seen.len() < size.min(13usize.pow(5)) {
+        let hand = (0..5)
+            .map(|_| *rng.pick(CARDS_FACES_P1))
+            .collect::<String>();
+        if seen.insert(hand.clone()) {
+            input += &format!("{hand} {}\n", rng.range(1..=1000));
+        }
+    }
+    input
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+pub struct Day07;
 
-    const TEST_INPUT: &str = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
+impl Solution for Day07 {
+    type Parsed = Vec<(ParsedHand, i64)>;
 
-    #[test]
-    fn test_parse1() {
-        assert_eq!(part1(TEST_INPUT), 6440);
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_bet(l).map_err(|e| e.on_line(i + 1)))
+            .collect()
     }
 
-    #[test]
-    fn test_parse2() {
-        assert_eq!(part2(TEST_INPUT), 5905);
+    fn part1(bets: &Self::Parsed) -> Answer {
+        total_winnings(bets, Rules::Part1)
     }
 
-    #[test]
-    fn test_real_part1() {
-        assert_eq!(part1(INPUT), 246409899);
-    }
-    #[test]
-    fn test_real_part2() {
-        assert_ne!(part2(INPUT), 244990514);
+    fn part2(bets: &Self::Parsed) -> Answer {
+        total_winnings(bets, Rules::Part2)
     }
 }
-
-fn main() {
-    println!("part1 {}", part1(INPUT));
-    println!("part2 {}", part2(INPUT));
-}
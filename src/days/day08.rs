@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use crate::coords::Turn;
+use crate::math::lcm_all;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day08/input.txt");
+
+/// Index of the node taken at a fork.
+fn idx(turn: &Turn) -> usize {
+    match turn {
+        Turn::Left => 0,
+        Turn::Right => 1,
+    }
+}
+
+type AdjList = HashMap<String, Vec<String>>;
+
+fn parse_node(line: &str) -> Result<(&str, Vec<&str>), ParseError> {
+    let (from, to) = line
+        .split_once('=')
+        .ok_or_else(|| ParseError::after(line, line, "`=`"))?;
+    let to = to
+        .trim_matches(|c: char| c == '(' || c == ')' || c.is_whitespace())
+        .split(',')
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    if to.len() != 2 {
+        return Err(ParseError::at(line, line, "`(LEFT, RIGHT)`"));
+    }
+    Ok((from.trim(), to))
+}
+
+fn parse(input: &str) -> Result<(Vec<Turn>, AdjList), ParseError> {
+    let mut lines = input.lines();
+    let direction_line = lines.next().unwrap_or_default();
+    if direction_line.is_empty() {
+        return Err(ParseError::after(direction_line, direction_line, "`L` or `R`").on_line(1));
+    }
+    let directions = direction_line
+        .char_indices()
+        .map(|(i, c)| {
+            c.try_into().map_err(|_| {
+                ParseError::at(
+                    direction_line,
+                    &direction_line[i..i + c.len_utf8()],
+                    "`L` or `R`",
+                )
+                .on_line(1)
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    let mut nodes = Vec::new();
+    for (i, line) in lines.enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let (from, to) = parse_node(line).map_err(|e| e.on_line(i + 2))?;
+        nodes.push((i + 2, line, from, to));
+    }
+    let adj_list: AdjList = nodes
+        .iter()
+        .map(|(_, _, from, to)| (from.to_string(), to.iter().map(|t| t.to_string()).collect()))
+        .collect();
+    for (line_number, line, _, to) in &nodes {
+        if let Some(unknown) = to.iter().find(|t| !adj_list.contains_key(**t)) {
+            return Err(
+                ParseError::at(line, unknown, "a node of the network").on_line(*line_number)
+            );
+        }
+    }
+    Ok((directions, adj_list))
+}
+
+/// A network for `size` directions, or fewer when the node names run out, walked by 2 to 6
+/// ghosts.
+///
+/// Each ghost's path has its own nodes and reaches its `Z` node after a prime number of
+/// rounds of directions, then loops back to the node after its start. That is the shape
+/// part 2 relies on. Nodes off the path sit on the side not taken.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let letters = || b'A'..=b'Z';
+    let mut names = Vec::new();
+    let mut prefixes = Vec::new();
+    for a in letters() {
+        for b in letters() {
+            prefixes.push(String::from_utf8(vec![a, b]).unwrap());
+            for c in b'B'..=b'Y' {
+                names.push(String::from_utf8(vec![a, b, c]).unwrap());
+            }
+        }
+    }
+    rng.shuffle(&mut names);
+    prefixes.retain(|p| p != "AA" && p != "ZZ");
+    rng.shuffle(&mut prefixes);
+    let mut periods = vec![2, 3, 5, 7, 11, 13, 17, 19];
+    rng.shuffle(&mut periods);
+    periods.truncate(rng.range(2..=6) as usize);
+
+    let len = size.clamp(1, names.len() / periods.iter().sum::<usize>());
+    let directions = (0..len)
+        .map(|_| *rng.pick(&[Turn::Left, Turn::Right]))
+        .collect::<Vec<_>>();
+    let mut names = names.into_iter();
+    let mut nodes = Vec::new();
+    for (ghost, period) in periods.into_iter().enumerate() {
+        let prefix = if ghost == 0 { "AA" } else { &prefixes[ghost] };
+        let end = if ghost == 0 {
+            "ZZZ".to_owned()
+        } else {
+            format!("{prefix}Z")
+        };
+        let steps = period * len;
+        let mut path = vec![format!("{prefix}A")];
+        path.extend(names.by_ref().take(steps - 1));
+        path.push(end);
+        for (i, node) in path.iter().enumerate() {
+            let next = &path[if i == steps { 1 } else { i + 1 }];
+            let other = rng.pick(&path);
+            nodes.push(match directions[i % len] {
+                Turn::Left => format!("{node} = ({next}, {other})\n"),
+                Turn::Right => format!("{node} = ({other}, {next})\n"),
+            });
+        }
+    }
+    rng.shuffle(&mut nodes);
+    let directions = directions
+        .iter()
+        .map(|d| match d {
+            Turn::Left => 'L',
+            Turn::Right => 'R',
+        })
+        .collect::<String>();
+    format!("{directions}\n\n{}", nodes.concat())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = (Vec<Turn>, AdjList);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    /// 0 when there is no `AAA` to start from.
+    fn part1((directions, adj_list): &Self::Parsed) -> Answer {
+        if !adj_list.contains_key("AAA") {
+            return 0;
+        }
+        let mut current_loc = "AAA";
+        let mut steps = 0;
+        let mut direction_iter = directions.iter().cycle();
+        while current_loc != "ZZZ" {
+            current_loc = &adj_list.get(current_loc).unwrap()[idx(direction_iter.next().unwrap())];
+            steps += 1;
+        }
+        steps
+    }
+
+    /// 0 when no node ends with `A`.
+    fn part2((directions, adj_list): &Self::Parsed) -> Answer {
+        let start_nodes = adj_list
+            .keys()
+            .filter(|s| s.ends_with('A'))
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+        let mut all_steps = Vec::new();
+        for mut start_node in start_nodes {
+            let mut steps = 0u64;
+            let mut direction_iter = directions.iter().cycle();
+            while !start_node.ends_with('Z') {
+                start_node =
+                    &adj_list.get(start_node).unwrap()[idx(direction_iter.next().unwrap())];
+                steps += 1;
+            }
+            all_steps.push(steps)
+        }
+        lcm_all(all_steps).map_or(0, |steps| steps as i64)
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = parse("\n\nAAA = (AAA, AAA)\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 1: expected `L` or `R`, found nothing"
+    );
+    let err = parse("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, CCC)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 4, column 13: expected a node of the network, found `CCC`"
+    );
+}
+
+#[test]
+fn test_no_start() {
+    let parsed = parse("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!((Day08::part1(&parsed), Day08::part2(&parsed)), (0, 0));
+}
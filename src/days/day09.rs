@@ -1,18 +1,22 @@
 use std::str::FromStr;
 
-const INPUT: &str = include_str!("day09/input.txt");
+use crate::parse::{parse_lines, parse_token, ParseError};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
 
-#[derive(PartialEq, Eq, Debug)]
-struct Sequence(Vec<Vec<i64>>);
+pub const INPUT: &str = include_str!("day09/input.txt");
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Sequence(Vec<Vec<i64>>);
 
 impl FromStr for Sequence {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Sequence(vec![s
             .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect()]))
+            .map(|v| parse_token(s, v, "a number"))
+            .collect::<Result<_, _>>()?]))
     }
 }
 
@@ -106,40 +110,36 @@ fn test_extrapolate() {
     );
 }
 
-fn part1(input: &str) -> i64 {
-    input
-        .lines()
-        .map(|l| l.parse::<Sequence>().unwrap().next())
-        .sum()
-}
-
-fn part2(input: &str) -> i64 {
+/// `size` sequences of the first 21 values of polynomials of degree up to 5.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let coefs = (0..=rng.range(0..=5))
+            .map(|_| rng.range(-9..=9))
+            .collect::<Vec<_>>();
+        let values = (0..21)
+            .map(|x| coefs.iter().rev().fold(0, |acc, c| acc * x + c).to_string())
+            .collect::<Vec<_>>();
+        input += &values.join(" ");
+        input.push('\n');
+    }
     input
-        .lines()
-        .map(|l| l.parse::<Sequence>().unwrap().prev())
-        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+pub struct Day09;
 
-    const TEST_INPUT: &str = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
+impl Solution for Day09 {
+    type Parsed = Vec<Sequence>;
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), 114);
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
     }
 
-    #[test]
-    fn test_parse2() {
-        assert_eq!(part2(TEST_INPUT), 2);
+    fn part1(sequences: &Self::Parsed) -> Answer {
+        sequences.iter().cloned().map(|mut s| s.next()).sum()
     }
-}
 
-fn main() {
-    println!("part1 {}", part1(INPUT));
-    println!("part2 {}", part2(INPUT));
+    fn part2(sequences: &Self::Parsed) -> Answer {
+        sequences.iter().cloned().map(|mut s| s.prev()).sum()
+    }
 }
@@ -0,0 +1,579 @@
+use core::fmt;
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+use strum::IntoEnumIterator;
+
+use crate::coords::{Coords, Direction4};
+use crate::grid::{Map, Tile};
+use crate::observe::{Observer, Search, Step};
+use crate::parse::ParseError;
+use crate::pathfind::bfs;
+use crate::render::{heat, Cell, Image, WHITE};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day10/input.txt");
+
+#[derive(PartialEq, Eq)]
+pub enum Pipe {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Pipe::NS),
+            '-' => Ok(Pipe::EW),
+            'L' => Ok(Pipe::NE),
+            'J' => Ok(Pipe::NW),
+            '7' => Ok(Pipe::SW),
+            'F' => Ok(Pipe::SE),
+            '.' => Ok(Pipe::Ground),
+            'S' => Ok(Pipe::Start),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Tile for Pipe {
+    const EXPECTED: &'static str = "a pipe out of `|-LJ7F`, ground `.` or the start `S`";
+
+    fn to_char(&self) -> char {
+        match self {
+            Pipe::NS => '|',
+            Pipe::EW => '-',
+            Pipe::NE => 'L',
+            Pipe::NW => 'J',
+            Pipe::SW => '7',
+            Pipe::SE => 'F',
+            Pipe::Ground => '.',
+            Pipe::Start => 'S',
+        }
+    }
+}
+
+impl core::fmt::Debug for Pipe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.glyph())
+    }
+}
+
+impl Pipe {
+    fn glyph(&self) -> char {
+        match self {
+            Self::NS => '║',
+            Self::EW => '═',
+            Self::NE => '╚',
+            Self::NW => '╝',
+            Self::SW => '╗',
+            Self::SE => '╔',
+            Self::Ground => '-',
+            Self::Start => 'S',
+        }
+    }
+
+    fn connections(&self) -> Option<Vec<Direction4>> {
+        match self {
+            Pipe::NS => Some(vec![Direction4::N, Direction4::S]),
+            Pipe::EW => Some(vec![Direction4::E, Direction4::W]),
+            Pipe::NE => Some(vec![Direction4::N, Direction4::E]),
+            Pipe::NW => Some(vec![Direction4::N, Direction4::W]),
+            Pipe::SW => Some(vec![Direction4::S, Direction4::W]),
+            Pipe::SE => Some(vec![Direction4::S, Direction4::E]),
+            Pipe::Ground => None,
+            Pipe::Start => None, // special
+        }
+    }
+}
+
+type PipeMap = Map<Pipe>;
+
+/// The map, which must have a single start tile.
+fn parse(input: &str) -> Result<PipeMap, ParseError> {
+    let map: PipeMap = input.parse()?;
+    let starts = map
+        .iter_coords()
+        .filter(|(_, pipe)| **pipe == Pipe::Start)
+        .map(|(coords, _)| coords)
+        .take(2)
+        .collect::<Vec<_>>();
+    if starts.is_empty() {
+        return Err(ParseError {
+            line: map.height() + 1,
+            column: 1,
+            token: String::new(),
+            expected: "a start tile `S`".to_owned(),
+        });
+    }
+    if let Some(second) = starts.get(1) {
+        return Err(ParseError {
+            line: second.line as usize + 1,
+            column: second.col as usize + 1,
+            token: "S".to_owned(),
+            expected: "a single start tile".to_owned(),
+        });
+    }
+    Ok(map)
+}
+
+impl PipeMap {
+    fn get_start(&self) -> Coords {
+        self.position(|pipe| *pipe == Pipe::Start)
+            .expect("parsing checks there is a start")
+    }
+
+    /// Whether the tile at `coords` opens towards `dir`, the start opening everywhere.
+    fn opens(&self, coords: Coords, dir: Direction4) -> bool {
+        match self.get(coords) {
+            Some(Pipe::Start) => true,
+            Some(pipe) => pipe.connections().is_some_and(|v| v.contains(&dir)),
+            None => false,
+        }
+    }
+
+    fn can_move(&self, coords: &Coords) -> Vec<Coords> {
+        Direction4::iter()
+            .filter(|&dir| {
+                self.opens(*coords, dir) && self.opens(*coords + dir.offset(), dir.opposite())
+            })
+            .map(|dir| *coords + dir.offset())
+            .collect()
+    }
+
+    /// Steps from the start to every tile of the loop, reporting each step of the search
+    /// to `observe`.
+    fn depth_map(&self, observe: &mut Observer) -> HashMap<Coords, i64> {
+        let start = self.get_start();
+        // Depths as `bfs` finds them, to tell when a round is over
+        let mut depths = HashMap::from([(start, 0)]);
+        let mut round = 0;
+        let dmap = bfs(
+            [start],
+            |c| {
+                let depth = depths[c];
+                if depth > round {
+                    observe(Step::Round);
+                    round = depth;
+                }
+                observe(Step::Visit { at: *c, depth });
+                let next = self.can_move(c);
+                for &n in &next {
+                    if let Entry::Vacant(entry) = depths.entry(n) {
+                        entry.insert(depth + 1);
+                        observe(Step::Queue(n));
+                    }
+                }
+                next
+            },
+            |_| false,
+        )
+        .into_distances();
+        observe(Step::Round);
+        dmap
+    }
+
+    fn count_in(&self, lop: Vec<Coords>) -> i64 {
+        self.inside_tiles(lop, &mut |_| {}).len() as i64
+    }
+
+    /// Tiles enclosed by the loop `lop`, line by line.
+    fn inside_tiles(&self, lop: Vec<Coords>, observe: &mut Observer) -> Vec<Coords> {
+        let mut inside_tiles = Vec::new();
+        for (line, content) in self.0.iter().enumerate() {
+            let mut inside = false;
+            for (col, p) in content.iter().enumerate() {
+                let here = Coords {
+                    line: line as i64,
+                    col: col as i64,
+                };
+                match p {
+                    Pipe::NS | Pipe::NE | Pipe::NW => {
+                        if lop.contains(&here) {
+                            inside = !inside;
+                        } else if inside {
+                            crate::trace!("inside", at = here);
+                            inside_tiles.push(here);
+                            observe(Step::Mark(here));
+                        }
+                    }
+                    Pipe::Start => {
+                        let above = here + Direction4::N.offset();
+                        if lop.contains(&above)
+                            && self.get(above).is_some_and(|p| {
+                                p.connections().is_some_and(|v| v.contains(&Direction4::S))
+                            })
+                        {
+                            inside = !inside;
+                        }
+                    }
+                    _ => {
+                        if !lop.contains(&here) && inside {
+                            crate::trace!("inside", at = here);
+                            inside_tiles.push(here);
+                            observe(Step::Mark(here));
+                        }
+                    }
+                }
+            }
+            observe(Step::Round);
+        }
+        inside_tiles
+    }
+
+    /// Length of the loop through the start, walking it.
+    fn loop_len(&self) -> i64 {
+        let start = self.get_start();
+        let (mut prev, mut here) = (start, self.can_move(&start)[0]);
+        let mut len = 1;
+        while here != start {
+            let next = self
+                .can_move(&here)
+                .into_iter()
+                .find(|&c| c != prev)
+                .unwrap();
+            (prev, here) = (here, next);
+            len += 1;
+        }
+        len
+    }
+
+    /// Tiles enclosed by the loop `lop`, found by flooding the outside.
+    ///
+    /// The flood runs on a grid twice as fine, so that it can squeeze between pipes: tile
+    /// `line`, `col` is at `2 * line + 1`, `2 * col + 1`, and what lies between two tiles is
+    /// blocked when they are linked by the loop.
+    fn inside_tiles_by_flood_fill(
+        &self,
+        lop: &HashSet<Coords>,
+        observe: &mut Observer,
+    ) -> Vec<Coords> {
+        let (height, width) = (self.0.len() as i64, self.0[0].len() as i64);
+        let fine = |c: Coords| Coords {
+            line: 2 * c.line + 1,
+            col: 2 * c.col + 1,
+        };
+        let mut blocked = HashSet::new();
+        for &tile in lop {
+            blocked.insert(fine(tile));
+            for next in self.can_move(&tile) {
+                if lop.contains(&next) && self.can_move(&next).contains(&tile) {
+                    blocked.insert(Coords {
+                        line: 2 * tile.line + 1 + (next.line - tile.line),
+                        col: 2 * tile.col + 1 + (next.col - tile.col),
+                    });
+                }
+            }
+        }
+
+        let origin = Coords { line: 0, col: 0 };
+        let mut outside = HashSet::from([origin]);
+        let mut frontier = vec![origin];
+        let mut depth = 0;
+        while !frontier.is_empty() {
+            let mut next_frontier = Vec::new();
+            for here in frontier {
+                if here.line % 2 == 1 && here.col % 2 == 1 {
+                    let tile = Coords {
+                        line: here.line / 2,
+                        col: here.col / 2,
+                    };
+                    observe(Step::Visit { at: tile, depth });
+                }
+                for dir in Direction4::iter() {
+                    let next = here + dir.offset();
+                    if (0..=2 * height).contains(&next.line)
+                        && (0..=2 * width).contains(&next.col)
+                        && !blocked.contains(&next)
+                        && outside.insert(next)
+                    {
+                        next_frontier.push(next);
+                    }
+                }
+            }
+            observe(Step::Round);
+            frontier = next_frontier;
+            depth += 1;
+        }
+
+        let mut inside_tiles = Vec::new();
+        for line in 0..height {
+            for col in 0..width {
+                let tile = Coords { line, col };
+                if !lop.contains(&tile) && !outside.contains(&fine(tile)) {
+                    inside_tiles.push(tile);
+                    observe(Step::Mark(tile));
+                }
+            }
+        }
+        inside_tiles
+    }
+}
+
+#[test]
+fn it_parses() {
+    let s = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+    let map = s.parse::<Map<Pipe>>().unwrap();
+    assert_eq!(map.to_string(), format!("{s}\n"));
+    assert_eq!(Day10::parse(INPUT).unwrap().to_string(), INPUT);
+}
+
+#[test]
+fn test_reference() {
+    for (input, inside) in [
+        (include_str!("../../examples/day10/3.txt"), 4),
+        (include_str!("../../examples/day10/5.txt"), 10),
+        (INPUT, 407),
+    ] {
+        let map = input.parse::<PipeMap>().unwrap();
+        assert_eq!(Day10Reference::part1(&map), Day10::part1(&map));
+        assert_eq!(Day10Reference::part2(&map), inside);
+    }
+}
+
+#[test]
+fn test_parse_start() {
+    let err = Day10::parse(".F7\n.LJ\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 3, column 1: expected a start tile `S`, found nothing"
+    );
+    let err = Day10::parse("SF7\n.LS\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: expected a single start tile, found `S`"
+    );
+}
+
+#[test]
+fn test_observe() {
+    let map = include_str!("../../examples/day10/1.txt")
+        .parse::<PipeMap>()
+        .unwrap();
+    let mut steps = Vec::new();
+    let dmap = map.depth_map(&mut |step| steps.push(step));
+    let visits = steps
+        .iter()
+        .filter_map(|s| match s {
+            Step::Visit { at, depth } => Some((*at, *depth)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    assert_eq!(visits, dmap);
+    // Every tile but the start is queued once
+    let queued = steps.iter().filter(|s| matches!(s, Step::Queue(_))).count();
+    assert_eq!(queued, dmap.len() - 1);
+    // One round per depth, the farthest tile being 4 steps away
+    assert_eq!(steps.iter().filter(|&&s| s == Step::Round).count(), 5);
+}
+
+/// Whether the blob of `cells` can take the cell at `line`, `col` and keep an outline that is
+/// a single loop: its neighbours in the blob, clockwise, must form one run.
+fn can_grow(cells: &[Vec<bool>], line: usize, col: usize) -> bool {
+    const RING: [(i64, i64); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+    ];
+    let in_blob = RING.map(|(dl, dc)| {
+        let (l, c) = (line as i64 + dl, col as i64 + dc);
+        l >= 0
+            && c >= 0
+            && cells
+                .get(l as usize)
+                .and_then(|row| row.get(c as usize))
+                .is_some_and(|&cell| cell)
+    });
+    let runs = (0..8)
+        .filter(|&i| in_blob[i] && !in_blob[(i + 7) % 8])
+        .count();
+    runs == 1 || in_blob.iter().all(|&cell| cell)
+}
+
+/// The outline of a random blob of cells of a `size` by `size` grid, among junk pipes.
+///
+/// The tiles are the corners of the cells, so the tiles inside the loop are the corners
+/// inside the blob.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut cells = vec![vec![false; size]; size];
+    let mut blob = vec![(size / 2, size / 2)];
+    cells[size / 2][size / 2] = true;
+    for _ in 0..size * size * 2 {
+        let (line, col) = *rng.pick(&blob);
+        let (dl, dc) = *rng.pick(&[(-1, 0), (1, 0), (0, -1), (0, 1)]);
+        let (Some(line), Some(col)) = (line.checked_add_signed(dl), col.checked_add_signed(dc))
+        else {
+            continue;
+        };
+        if line < size && col < size && !cells[line][col] && can_grow(&cells, line, col) {
+            cells[line][col] = true;
+            blob.push((line, col));
+        }
+    }
+
+    let is_in = |line: Option<usize>, col: Option<usize>| {
+        line.zip(col)
+            .is_some_and(|(l, c)| l < size && c < size && cells[l][c])
+    };
+    // Directions the loop leaves each corner by
+    let mut links = vec![vec![Vec::new(); size + 1]; size + 1];
+    for &(l, c) in &blob {
+        if !is_in(l.checked_sub(1), Some(c)) {
+            links[l][c].push('E');
+            links[l][c + 1].push('W');
+        }
+        if !is_in(Some(l + 1), Some(c)) {
+            links[l + 1][c].push('E');
+            links[l + 1][c + 1].push('W');
+        }
+        if !is_in(Some(l), c.checked_sub(1)) {
+            links[l][c].push('S');
+            links[l + 1][c].push('N');
+        }
+        if !is_in(Some(l), Some(c + 1)) {
+            links[l][c + 1].push('S');
+            links[l + 1][c + 1].push('N');
+        }
+    }
+    let mut tiles = links
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|dirs| {
+                    let has = |d| dirs.contains(&d);
+                    match (has('N'), has('S'), has('E'), has('W')) {
+                        (true, true, _, _) => '|',
+                        (_, _, true, true) => '-',
+                        (true, _, true, _) => 'L',
+                        (true, _, _, true) => 'J',
+                        (_, true, _, true) => '7',
+                        (_, true, true, _) => 'F',
+                        _ => *rng.pick(&['.', '.', '.', '|', '-', 'L', 'J', '7', 'F']),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let corners = (0..=size)
+        .flat_map(|l| (0..=size).map(move |c| (l, c)))
+        .filter(|&(l, c)| !links[l][c].is_empty())
+        .collect::<Vec<_>>();
+    let (l, c) = *rng.pick(&corners);
+    tiles[l][c] = 'S';
+    // Only the loop may connect to the start
+    for (dl, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+        if let (Some(nl), Some(nc)) = (l.checked_add_signed(dl), c.checked_add_signed(dc)) {
+            if nl <= size && nc <= size && links[nl][nc].is_empty() {
+                tiles[nl][nc] = '.';
+            }
+        }
+    }
+    tiles
+        .into_iter()
+        .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
+
+/// The pipes, the loop coloured by its distance to the start, and the tiles inside it.
+pub fn render(input: &str) -> Result<Image, ParseError> {
+    let map = Day10::parse(input)?;
+    let dmap = map.depth_map(&mut |_| {});
+    let farthest = dmap.values().copied().max().unwrap_or_default();
+    let inside = map.inside_tiles(dmap.keys().copied().collect(), &mut |_| {});
+    let mut image = map.render(|pipe| Cell {
+        fill: [40, 40, 40],
+        glyph: Some(pipe.glyph()),
+    });
+    image
+        .layer(1.0, |c| dmap.get(&c).map(|&depth| heat(depth, farthest)))
+        .layer(0.7, |c| inside.contains(&c).then_some(WHITE));
+    Ok(image)
+}
+
+/// The breadth-first search of `depth_map`, the flood fill and the scanline of the inside.
+pub const SEARCHES: &[Search] = &[
+    Search {
+        name: "bfs",
+        run: |input, observe| {
+            Day10::parse(input)?.depth_map(observe);
+            Ok(())
+        },
+    },
+    Search {
+        name: "fill",
+        run: |input, observe| {
+            let map = Day10::parse(input)?;
+            let lop = map.depth_map(&mut |_| {}).into_keys().collect();
+            map.inside_tiles_by_flood_fill(&lop, observe);
+            Ok(())
+        },
+    },
+    Search {
+        name: "scanline",
+        run: |input, observe| {
+            let map = Day10::parse(input)?;
+            let lop = map.depth_map(&mut |_| {}).into_keys().collect();
+            map.inside_tiles(lop, observe);
+            Ok(())
+        },
+    },
+];
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = PipeMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Answer {
+        let dmap = map.depth_map(&mut |_| {});
+        dmap.into_values().max().unwrap()
+    }
+
+    fn part2(map: &Self::Parsed) -> Answer {
+        let dmap = map.depth_map(&mut |_| {});
+        let lop = dmap.into_keys().collect();
+        map.count_in(lop)
+    }
+}
+
+/// Walks the loop instead of searching it, and floods the outside instead of scanning lines.
+pub struct Day10Reference;
+
+impl Solution for Day10Reference {
+    type Parsed = PipeMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Answer {
+        map.loop_len() / 2
+    }
+
+    fn part2(map: &Self::Parsed) -> Answer {
+        let lop = map.depth_map(&mut |_| {}).into_keys().collect();
+        map.inside_tiles_by_flood_fill(&lop, &mut |_| {}).len() as i64
+    }
+}
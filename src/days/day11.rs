@@ -0,0 +1,279 @@
+use core::fmt;
+use std::collections::HashSet;
+
+use crate::algo::uniq_pairs;
+use crate::coords::Coords;
+use crate::grid::{Map, Tile};
+use crate::parse::ParseError;
+use crate::render::{Cell, Image, BLACK, WHITE};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+use crate::sparse::SparseMap;
+
+pub const INPUT: &str = include_str!("day11/input.txt");
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Space {
+    Empty,
+    Galaxy,
+}
+
+impl TryFrom<char> for Space {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Space::Empty),
+            '#' => Ok(Space::Galaxy),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Tile for Space {
+    const EXPECTED: &'static str = "empty space `.` or a galaxy `#`";
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Galaxy => '#',
+        }
+    }
+}
+
+impl fmt::Debug for Space {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+type Universe = Map<Space>;
+
+impl Universe {
+    fn galaxies(&self) -> Vec<Coords> {
+        self.iter_coords()
+            .filter(|(_, space)| **space == Space::Galaxy)
+            .map(|(coords, _)| coords)
+            .collect()
+    }
+
+    fn galaxies_lines_cols(&self) -> (HashSet<i64>, HashSet<i64>) {
+        let lines_with_galaxies = self
+            .rows()
+            .enumerate()
+            .filter(|(_, spaces)| spaces.contains(&Space::Galaxy))
+            .map(|(line, _)| line as i64)
+            .collect();
+        let cols_with_galaxies = self
+            .cols()
+            .enumerate()
+            .filter_map(|(col, mut spaces)| {
+                spaces
+                    .any(|space| *space == Space::Galaxy)
+                    .then_some(col as i64)
+            })
+            .collect();
+        (lines_with_galaxies, cols_with_galaxies)
+    }
+
+    #[cfg(test)]
+    fn expand(&mut self, factor: i64) {
+        let (lines_with_galaxies, cols_with_galaxies) = self.galaxies_lines_cols();
+        // insert lines
+        let len = self.0.len();
+        for i in (0..len).rev() {
+            if !lines_with_galaxies.contains(&(i as i64)) {
+                for _ in 0..(factor - 1) {
+                    self.0.insert(i, self.empty_line())
+                }
+            }
+        }
+        // insert cols
+        for line in self.0.iter_mut() {
+            let len = line.len();
+            for i in (0..len).rev() {
+                if !cols_with_galaxies.contains(&(i as i64)) {
+                    for _ in 0..(factor - 1) {
+                        line.insert(i, Space::Empty);
+                    }
+                }
+            }
+        }
+    }
+
+    fn expand_coords(&self, coords: Coords, factor: i64) -> Coords {
+        let (lines_with_galaxies, cols_with_galaxies) = self.galaxies_lines_cols();
+        let lines = lines_with_galaxies
+            .iter()
+            .filter(|&&i| i < coords.line)
+            .count() as i64;
+        let new_line = (coords.line - lines) * (factor - 1) + coords.line;
+        let cols = cols_with_galaxies
+            .iter()
+            .filter(|&&i| i < coords.col)
+            .count() as i64;
+        let new_col = (coords.col - cols) * (factor - 1) + coords.col;
+        Coords {
+            line: new_line,
+            col: new_col,
+        }
+    }
+
+    /// The galaxies once every empty line and column is `factor` times as large, however
+    /// far apart that puts them.
+    fn expanded(&self, factor: i64) -> SparseMap<Space> {
+        let mut expanded = SparseMap::new(Space::Empty);
+        for galaxy in self.galaxies() {
+            expanded.set(self.expand_coords(galaxy, factor), Space::Galaxy);
+        }
+        expanded
+    }
+
+    /// Lines are all as wide, parsing makes sure of it.
+    #[cfg(test)]
+    fn empty_line(&self) -> Vec<Space> {
+        vec![Space::Empty; self.width()]
+    }
+}
+
+#[test]
+fn test_expand() {
+    let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    let expanded = "....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......";
+
+    let mut universe = input.parse::<Universe>().unwrap();
+    universe.expand(2);
+
+    assert_eq!(universe, expanded.parse().unwrap());
+}
+
+#[test]
+fn test_display() {
+    // The input lacks the last newline, that `Display` always writes
+    let written = Day11::parse(INPUT).unwrap().to_string();
+    assert!(written.lines().eq(INPUT.lines()));
+}
+
+#[test]
+fn test_expanded() {
+    let universe = include_str!("../../examples/day11/1.txt")
+        .parse::<Universe>()
+        .unwrap();
+    let sparse = universe.expanded(2);
+    assert_eq!(sparse.len(), 9);
+    // Far too large to be expanded densely
+    let (_, bottom_right) = universe.expanded(1_000_000).bounds().unwrap();
+    assert!(bottom_right.line > 1_000_000);
+    let mut dense = universe;
+    dense.expand(2);
+    assert_eq!(sparse.to_map(), dense);
+}
+
+#[test]
+fn test_galaxies() {
+    let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    let mut universe = input.parse::<Universe>().unwrap();
+    universe.expand(2);
+    let galaxies = universe.galaxies();
+    assert_eq!(galaxies[4].dist_square(&galaxies[8]), 9);
+    assert_eq!(galaxies[0].dist_square(&galaxies[6]), 15);
+    assert_eq!(galaxies[2].dist_square(&galaxies[5]), 17);
+}
+
+fn total_distance(universe: &Universe, factor: i64) -> i64 {
+    let galaxies = universe
+        .expanded(factor)
+        .iter_coords()
+        .map(|(coords, _)| coords)
+        .collect::<Vec<_>>();
+    let mut total_distance = 0;
+    let pairs = uniq_pairs(&galaxies);
+    for pair in pairs {
+        let dist = pair.0.dist_square(&pair.1);
+        total_distance += dist;
+    }
+    total_distance
+}
+
+/// A `size` by `size` image with galaxies in a few tiles, and some rows and columns empty.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let lines = (0..size).map(|_| rng.chance(0.9)).collect::<Vec<_>>();
+    let cols = (0..size).map(|_| rng.chance(0.9)).collect::<Vec<_>>();
+    let mut input = String::new();
+    for &line in &lines {
+        for &col in &cols {
+            input.push(if line && col && rng.chance(0.03) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// The galaxies, over the empty lines and columns that expand.
+pub fn render(input: &str) -> Result<Image, ParseError> {
+    let universe = Day11::parse(input)?;
+    let (lines, cols) = universe.galaxies_lines_cols();
+    let mut image = universe.render(|space| match space {
+        Space::Galaxy => Cell {
+            fill: WHITE,
+            glyph: Some('#'),
+        },
+        Space::Empty => BLACK.into(),
+    });
+    image.layer(0.4, |c| {
+        (!lines.contains(&c.line) || !cols.contains(&c.col)).then_some([60, 60, 200])
+    });
+    Ok(image)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Universe;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(universe: &Self::Parsed) -> Answer {
+        total_distance(universe, 2)
+    }
+
+    fn part2(universe: &Self::Parsed) -> Answer {
+        total_distance(universe, 1_000_000)
+    }
+}
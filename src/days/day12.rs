@@ -1,7 +1,12 @@
 use rayon::prelude::*;
 use std::{collections::HashMap, str::FromStr};
 
-const INPUT: &str = include_str!("day12/input.txt");
+use crate::grid::Tile;
+use crate::parse::{parse_lines, parse_token, ParseError};
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+
+pub const INPUT: &str = include_str!("day12/input.txt");
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 enum PartStatus {
@@ -10,19 +15,23 @@ enum PartStatus {
     Unknown,
 }
 
-impl From<char> for PartStatus {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for PartStatus {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '.' => Self::Operational,
-            '#' => Self::Damaged,
-            '?' => Self::Unknown,
-            o => panic!("`{}` is not a valid PartStatus", o),
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(()),
         }
     }
 }
 
-impl Into<char> for PartStatus {
-    fn into(self) -> char {
+impl Tile for PartStatus {
+    const EXPECTED: &'static str = "a spring `.`, `#` or `?`";
+
+    fn to_char(&self) -> char {
         match self {
             PartStatus::Operational => '.',
             PartStatus::Damaged => '#',
@@ -31,24 +40,34 @@ impl Into<char> for PartStatus {
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
-struct Line {
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Line {
     parts: Vec<PartStatus>,
     damaged_groups: Vec<i64>,
 }
 
 impl FromStr for Line {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut split = s.split_whitespace();
-        let parts = split.next().unwrap().chars().map(|c| c.into()).collect();
+        let record = split
+            .next()
+            .ok_or_else(|| ParseError::at(s, s, "a spring record"))?;
+        let parts = record
+            .char_indices()
+            .map(|(at, c)| {
+                PartStatus::try_from(c).map_err(|_| {
+                    ParseError::at(s, &record[at..at + c.len_utf8()], PartStatus::EXPECTED)
+                })
+            })
+            .collect::<Result<_, _>>()?;
         let mut damaged_groups = Vec::new();
         if let Some(damaged_group_data) = split.next() {
             damaged_groups = damaged_group_data
                 .split(',')
-                .map(|c| c.parse().unwrap())
-                .collect();
+                .map(|c| parse_token(s, c, "a group size"))
+                .collect::<Result<_, _>>()?;
         }
         Ok(Line {
             parts,
@@ -57,20 +76,59 @@ impl FromStr for Line {
     }
 }
 
+/// Counts already known, with how often they were looked up.
+struct Cache<K> {
+    counts: HashMap<K, i64>,
+    hits: usize,
+}
+
+impl<K: std::hash::Hash + Eq> Cache<K> {
+    fn new() -> Self {
+        Cache {
+            counts: HashMap::new(),
+            hits: 0,
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<i64> {
+        let count = self.counts.get(key).copied();
+        self.hits += count.is_some() as usize;
+        count
+    }
+
+    fn insert(&mut self, key: K, count: i64) {
+        self.counts.insert(key, count);
+    }
+
+    /// Every count is computed once, after a miss.
+    fn misses(&self) -> usize {
+        self.counts.len()
+    }
+}
+
 impl Line {
+    #[cfg(test)]
     fn arrangements_count(&self) -> i64 {
-        Self::better_count(&self.parts, &self.damaged_groups, &mut HashMap::new())
-        // self.arrangements_count_recursive(self.parts.clone(), &mut HashMap::new())
+        self.arrangements_count_cached().0
+    }
+
+    /// The count, with the hits and misses of its cache.
+    fn arrangements_count_cached(&self) -> (i64, usize, usize) {
+        let mut cache = Cache::new();
+        let count = Self::better_count(&self.parts, &self.damaged_groups, &mut cache);
+        crate::trace!(
+            "record",
+            arrangements = count,
+            hits = cache.hits,
+            misses = cache.misses()
+        );
+        (count, cache.hits, cache.misses())
     }
 
     fn valid_arrangement(&self, parts: &[PartStatus]) -> bool {
-        let s = parts
-            .iter()
-            .copied()
-            .map(Into::<char>::into)
-            .collect::<String>();
+        let s = parts.iter().map(PartStatus::to_char).collect::<String>();
         let t = s
-            .split::<char>(PartStatus::Operational.into())
+            .split(PartStatus::Operational.to_char())
             .filter(|c| !c.is_empty())
             .map(|sub| sub.len() as i64)
             .collect::<Vec<_>>();
@@ -80,11 +138,10 @@ impl Line {
     fn arrangements_count_recursive(
         &self,
         parts: Vec<PartStatus>,
-        cache: &mut HashMap<Vec<PartStatus>, i64>,
+        cache: &mut Cache<Vec<PartStatus>>,
     ) -> i64 {
         if let Some(res) = cache.get(&parts) {
-            println!("cache hit");
-            return *res;
+            return res;
         }
         let ret;
         if let Some(next) = parts.iter().position(|p| *p == PartStatus::Unknown) {
@@ -132,7 +189,7 @@ impl Line {
     fn better_count(
         mut parts: &[PartStatus],
         counts: &[i64],
-        cache: &mut HashMap<(Vec<PartStatus>, Vec<i64>), i64>,
+        cache: &mut Cache<(Vec<PartStatus>, Vec<i64>)>,
     ) -> i64 {
         if let Some(skip) = parts.iter().position(|p| *p != PartStatus::Operational) {
             parts = &parts[skip..];
@@ -141,7 +198,7 @@ impl Line {
         }
 
         if let Some(ret) = cache.get(&(parts.to_vec(), counts.to_vec())) {
-            return *ret;
+            return ret;
         }
 
         let ret;
@@ -153,7 +210,7 @@ impl Line {
                 ret = 0;
             }
         } else if counts.is_empty() {
-            if parts.iter().any(|p| *p == PartStatus::Damaged) {
+            if parts.contains(&PartStatus::Damaged) {
                 ret = 0;
             } else {
                 ret = 1;
@@ -162,10 +219,7 @@ impl Line {
             if parts.len() < counts[0] as usize {
                 // can't have enough damaged parts
                 ret = 0;
-            } else if parts[..(counts[0] as usize)]
-                .iter()
-                .any(|p| *p == PartStatus::Operational)
-            {
+            } else if parts[..(counts[0] as usize)].contains(&PartStatus::Operational) {
                 // that would be 2 groups
                 ret = 0;
             } else
@@ -195,7 +249,7 @@ impl Line {
         }
 
         cache.insert((parts.to_vec(), counts.to_vec()), ret);
-        return ret;
+        ret
     }
 }
 
@@ -240,52 +294,115 @@ fn test_arrangement_count() {
     assert_eq!(s.arrangements_count(), 16384);
 }
 
-fn part1(input: &str) -> i64 {
-    input
-        .lines()
-        .map(|l| {
-            let mut record = l.parse::<Line>().unwrap();
-            record.simplify();
-            record.arrangements_count()
-        })
-        .sum()
+#[test]
+fn test_parse_error() {
+    let err = Day12::parse("#.? 1\n#x? 1\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 2: expected a spring `.`, `#` or `?`, found `x`"
+    );
+}
+
+#[test]
+fn test_cache_events() {
+    let events = crate::trace::capture(crate::trace::DEBUG, || {
+        Day12::part1(&Day12::parse("???.### 1,1,3\n.??..??...?##. 1,1,3\n").unwrap());
+    });
+    assert_eq!(events, ["day12 cache hits=6 misses=33"]);
 }
 
-fn part2(input: &str) -> i64 {
+/// `size` records of up to 6 groups, with about 40% of the springs unknown.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let groups = (0..rng.range(1..=6))
+            .map(|_| rng.range(1..=5))
+            .collect::<Vec<_>>();
+        let mut springs = ".".repeat(rng.range(0..=2) as usize);
+        for (i, &group) in groups.iter().enumerate() {
+            if i > 0 {
+                springs += &".".repeat(rng.range(1..=3) as usize);
+            }
+            springs += &"#".repeat(group as usize);
+        }
+        springs += &".".repeat(rng.range(0..=2) as usize);
+        let record = springs
+            .chars()
+            .map(|c| if rng.chance(0.4) { '?' } else { c })
+            .collect::<String>();
+        let groups = groups.iter().map(i64::to_string).collect::<Vec<_>>();
+        input += &format!("{record} {}\n", groups.join(","));
+    }
     input
-        .par_lines()
-        .map(|l| {
-            let mut record = l.parse::<Line>().unwrap();
-            record.unfold();
-            // record.simplify();
-            record.arrangements_count()
-        })
-        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+fn add_counts(a: (i64, usize, usize), b: (i64, usize, usize)) -> (i64, usize, usize) {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+}
+
+pub struct Day12;
 
-    const TEST_INPUT_1: &str = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
+impl Solution for Day12 {
+    type Parsed = Vec<Line>;
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT_1), 21);
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
     }
 
-    #[test]
-    fn test_parse2() {
-        assert_eq!(part2(TEST_INPUT_1), 525152);
+    fn part1(records: &Self::Parsed) -> Answer {
+        let totals = records
+            .iter()
+            .cloned()
+            .map(|mut record| {
+                record.simplify();
+                record.arrangements_count_cached()
+            })
+            .fold((0, 0, 0), add_counts);
+        crate::debug!("cache", hits = totals.1, misses = totals.2);
+        totals.0
+    }
+
+    fn part2(records: &Self::Parsed) -> Answer {
+        let totals = records
+            .par_iter()
+            .cloned()
+            .map(|mut record| {
+                record.unfold();
+                record.arrangements_count_cached()
+            })
+            .reduce(|| (0, 0, 0), add_counts);
+        crate::debug!("cache", hits = totals.1, misses = totals.2);
+        totals.0
     }
 }
 
-fn main() {
-    println!("part1 {}", part1(INPUT));
-    println!("part2 {}", part2(INPUT));
+/// Tries every way of filling in the unknown springs, so part 2 is out of reach.
+pub struct Day12Reference;
+
+impl Solution for Day12Reference {
+    type Parsed = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(records: &Self::Parsed) -> Answer {
+        records
+            .iter()
+            .map(|record| {
+                record.arrangements_count_recursive(record.parts.clone(), &mut Cache::new())
+            })
+            .sum()
+    }
+
+    fn part2(records: &Self::Parsed) -> Answer {
+        records
+            .iter()
+            .cloned()
+            .map(|mut record| {
+                record.unfold();
+                record.arrangements_count_recursive(record.parts.clone(), &mut Cache::new())
+            })
+            .sum()
+    }
 }
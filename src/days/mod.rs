@@ -0,0 +1,104 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+use crate::observe::Search;
+use crate::parse::ParseError;
+use crate::render::Image;
+use crate::rng::Rng;
+use crate::solution::{self, Solve};
+
+/// Entry points of a solved day, as seen by the runner.
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    pub parse: fn(&str) -> Result<Box<dyn Solve>, ParseError>,
+    /// A random valid input, `size` setting its number of lines or its width
+    pub generate: fn(&mut Rng, usize) -> String,
+    pub reference: Option<Reference>,
+    /// The input drawn as an image, for grid days
+    pub render: Option<Render>,
+    /// Searches that can be watched step by step
+    pub searches: &'static [Search],
+}
+
+pub type Render = fn(&str) -> Result<Image, ParseError>;
+
+/// A slow but plainly correct solver, to check a day's `Solution` against.
+pub struct Reference {
+    pub parse: fn(&str) -> Result<Box<dyn Solve>, ParseError>,
+    /// Parts it solves in reasonable time on small generated inputs
+    pub parts: &'static [u8],
+}
+
+macro_rules! day {
+    ($number:literal, $module:ident, $solution:ident) => {
+        Day {
+            number: $number,
+            input: $module::INPUT,
+            parse: solution::parse::<$module::$solution>,
+            generate: $module::generate,
+            reference: None,
+            render: None,
+            searches: &[],
+        }
+    };
+    ($number:literal, $module:ident, $solution:ident, $reference:ident, $parts:expr) => {
+        Day {
+            reference: Some(Reference {
+                parse: solution::parse::<$module::$reference>,
+                parts: &$parts,
+            }),
+            ..day!($number, $module, $solution)
+        }
+    };
+}
+
+/// Every registered day, in calendar order.
+pub const DAYS: &[Day] = &[
+    day!(1, day01, Day01),
+    day!(2, day02, Day02),
+    day!(3, day03, Day03),
+    day!(4, day04, Day04),
+    day!(5, day05, Day05, Day05Reference, [1, 2]),
+    day!(6, day06, Day06, Day06Reference, [1, 2]),
+    day!(7, day07, Day07),
+    day!(8, day08, Day08),
+    day!(9, day09, Day09),
+    Day {
+        render: Some(day10::render),
+        searches: day10::SEARCHES,
+        ..day!(10, day10, Day10, Day10Reference, [1, 2])
+    },
+    Day {
+        render: Some(day11::render),
+        ..day!(11, day11, Day11)
+    },
+    day!(12, day12, Day12, Day12Reference, [1]),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[test]
+fn test_generators() {
+    for day in DAYS {
+        for seed in 0..5 {
+            let input = (day.generate)(&mut Rng::new(seed), 12);
+            let parsed = (day.parse)(&input)
+                .unwrap_or_else(|e| panic!("day{:02} seed {seed}: {e}\n{input}", day.number));
+            parsed.part1();
+            parsed.part2();
+        }
+    }
+}
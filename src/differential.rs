@@ -0,0 +1,256 @@
+use std::{
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::report::panic_message;
+use advent_of_code_2023::days::{Day, Reference};
+use advent_of_code_2023::parse::ParseError;
+use advent_of_code_2023::rng::Rng;
+use advent_of_code_2023::solution::{Answer, Solve};
+
+type Parse = fn(&str) -> Result<Box<dyn Solve>, ParseError>;
+
+/// What a solver made of an input: its answer, or why it panicked.
+pub type Outcome = Result<Answer, String>;
+
+/// An input on which a day and its reference disagree.
+pub struct Mismatch {
+    pub seed: u64,
+    pub part: u8,
+    /// The generated input, shrunk as far as the disagreement allows
+    pub input: String,
+    pub answer: Outcome,
+    pub reference: Outcome,
+}
+
+/// Solves `part` of `input`, or `None` when it does not parse.
+fn outcome(parse: Parse, input: &str, part: u8) -> Option<Outcome> {
+    let parsed = parse(input).ok()?;
+    Some(
+        panic::catch_unwind(AssertUnwindSafe(|| match part {
+            1 => parsed.part1(),
+            _ => parsed.part2(),
+        }))
+        .map_err(panic_message),
+    )
+}
+
+fn disagree(day: &Day, reference: &Reference, input: &str, part: u8) -> Option<(Outcome, Outcome)> {
+    let answer = outcome(day.parse, input, part)?;
+    let expected = outcome(reference.parse, input, part)?;
+    (answer != expected).then_some((answer, expected))
+}
+
+/// Solves `part` of inputs generated from `seeds` with both the day and its reference,
+/// returning the first disagreement with its input shrunk.
+///
+/// Panics of either solver count as outcomes, but still print their message unless
+/// `QuietPanics` is held.
+pub fn check(
+    day: &Day,
+    part: u8,
+    seeds: Range<u64>,
+    size: usize,
+) -> Result<Option<Mismatch>, String> {
+    let reference = day
+        .reference
+        .as_ref()
+        .ok_or_else(|| format!("day {} has no reference solver", day.number))?;
+    let mismatch = seeds.into_iter().find_map(|seed| {
+        let input = (day.generate)(&mut Rng::new(seed), size);
+        let (answer, expected) = disagree(day, reference, &input, part)?;
+        let same_failure = |input: &str| {
+            disagree(day, reference, input, part).is_some_and(|(a, e)| {
+                a.is_err() == answer.is_err() && e.is_err() == expected.is_err()
+            })
+        };
+        let input = shrink(&input, same_failure);
+        let (answer, reference) = disagree(day, reference, &input, part)?;
+        Some(Mismatch {
+            seed,
+            part,
+            input,
+            answer,
+            reference,
+        })
+    });
+    Ok(mismatch)
+}
+
+type Hook = Box<dyn Fn(&panic::PanicHookInfo) + Sync + Send>;
+
+/// Keeps panics from printing their message until dropped, for when they are outcomes of
+/// `check` rather than worth a message each.
+pub struct QuietPanics(Option<Hook>);
+
+impl QuietPanics {
+    pub fn new() -> Self {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        QuietPanics(Some(hook))
+    }
+}
+
+impl Drop for QuietPanics {
+    fn drop(&mut self) {
+        if let Some(hook) = self.0.take() {
+            panic::set_hook(hook);
+        }
+    }
+}
+
+/// Smaller variants of `input`: without a chunk of lines, a token, a column of tokens, a
+/// character, or with a smaller number. Every variant is shorter or holds a smaller number, so shrinking ends.
+fn variants(input: &str) -> Vec<String> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let mut variants = Vec::new();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        for start in (0..lines.len()).step_by(chunk) {
+            let kept = [&lines[..start], &lines[(start + chunk).min(lines.len())..]].concat();
+            variants.push(kept.iter().map(|l| format!("{l}\n")).collect());
+        }
+        chunk /= 2;
+    }
+
+    let is_separator = |c: char| c.is_whitespace() || c == ',';
+    let mut token_start = None;
+    // Spans of the tokens of each column, with the separator before them if any
+    let mut columns: Vec<Vec<Range<usize>>> = Vec::new();
+    let mut column = 0;
+    for (i, c) in input.char_indices().chain([(input.len(), '\n')]) {
+        match (token_start, is_separator(c)) {
+            (None, false) => token_start = Some(i),
+            (Some(start), true) => {
+                token_start = None;
+                let from = input[..start]
+                    .char_indices()
+                    .next_back()
+                    .filter(|&(_, c)| c == ' ' || c == ',')
+                    .map_or(start, |(j, _)| j);
+                if columns.len() <= column {
+                    columns.push(Vec::new());
+                }
+                columns[column].push(from..i);
+                column += 1;
+                variants.push(format!("{}{}", &input[..from], &input[i..]));
+                let token = &input[start..i];
+                if let Ok(n) = token.parse::<u64>() {
+                    for smaller in [0, n / 2, n.saturating_sub(1)] {
+                        if smaller < n {
+                            variants.push(format!("{}{smaller}{}", &input[..start], &input[i..]));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        if c == '\n' {
+            column = 0;
+        }
+    }
+
+    // The same column dropped from every line, for rows that must stay as long as each other
+    for column in columns.iter().filter(|c| c.len() > 1) {
+        let mut kept = String::new();
+        let mut end = 0;
+        for span in column {
+            kept.push_str(&input[end..span.start]);
+            end = span.end;
+        }
+        kept.push_str(&input[end..]);
+        variants.push(kept);
+    }
+
+    for (i, c) in input.char_indices().filter(|&(_, c)| c != '\n') {
+        variants.push(format!("{}{}", &input[..i], &input[i + c.len_utf8()..]));
+    }
+    variants
+}
+
+/// Shrinks `input` greedily while it still `fails`.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut input = input.to_owned();
+    while let Some(smaller) = variants(&input).into_iter().find(|v| fails(v)) {
+        input = smaller;
+    }
+    input
+}
+
+#[test]
+fn test_shrink() {
+    let input = "a 12 7\nb 3,40\nc 9\n";
+    assert_eq!(shrink(input, |s| s.contains("b 3")), "b 3\n");
+    assert_eq!(
+        shrink(input, |s| s.split_whitespace().any(|t| t.parse() == Ok(9))),
+        "9\n"
+    );
+    let sum = |s: &str| {
+        s.split(|c: char| !c.is_ascii_digit())
+            .filter_map(|t| t.parse::<u64>().ok())
+            .sum::<u64>()
+    };
+    assert_eq!(shrink(input, |s| sum(s) >= 50), "50\n");
+    // Rows as long as each other lose a column at a time
+    let rows = |s: &str| {
+        let counts = s
+            .lines()
+            .map(|l| l.split_whitespace().count())
+            .collect::<Vec<_>>();
+        counts.len() == 2 && counts[0] == counts[1] && s.contains('4')
+    };
+    assert_eq!(shrink("a: 1 2 3\nb: 4 5 6\n", rows), "0\n4\n");
+}
+
+#[test]
+fn test_references() {
+    for day in advent_of_code_2023::days::DAYS {
+        let Some(reference) = &day.reference else {
+            continue;
+        };
+        for &part in reference.parts {
+            if let Some(m) = check(day, part, 0..10, 3).unwrap() {
+                panic!(
+                    "day{:02} part{part} seed {}: {:?} vs reference {:?}\n{}",
+                    day.number, m.seed, m.answer, m.reference, m.input
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+struct OffByOne(Box<dyn Solve>);
+
+#[cfg(test)]
+impl Solve for OffByOne {
+    fn part1(&self) -> Answer {
+        let answer = self.0.part1();
+        answer + (answer > 1) as Answer
+    }
+
+    fn part2(&self) -> Answer {
+        self.0.part2()
+    }
+}
+
+#[test]
+fn test_check_shrinks() {
+    let day6 = advent_of_code_2023::days::get(6).unwrap();
+    let wrong = Day {
+        parse: |input| {
+            Ok(Box::new(OffByOne((advent_of_code_2023::days::get(6)
+                .unwrap()
+                .parse)(input)?)))
+        },
+        reference: Some(Reference {
+            parse: day6.reference.as_ref().unwrap().parse,
+            parts: &[1],
+        }),
+        ..*day6
+    };
+    let m = check(&wrong, 1, 0..1, 3).unwrap().unwrap();
+    assert_eq!((m.answer, m.reference), (Ok(3), Ok(2)));
+    assert_eq!(m.input, "Time:3\nDistance:0\n");
+}
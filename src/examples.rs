@@ -0,0 +1,151 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use advent_of_code_2023::days::Day;
+use advent_of_code_2023::solution::Answer;
+
+/// A puzzle example, stored as `examples/dayNN/N.txt` with its expected answers
+/// in `examples/dayNN/answers.toml`.
+pub struct Example {
+    pub number: u32,
+    pub input: String,
+    pub expected: Expected,
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<Answer> {
+        match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("day{day:02}"))
+}
+
+fn load_expected(day: u8) -> Result<BTreeMap<u32, Expected>, String> {
+    let path = day_dir(day).join("answers.toml");
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let data = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let expected: BTreeMap<String, Expected> =
+        toml::from_str(&data).map_err(|e| format!("{}: {e}", path.display()))?;
+    expected
+        .into_iter()
+        .map(|(n, e)| {
+            n.parse()
+                .map(|n| (n, e))
+                .map_err(|_| format!("{}: `{n}` is not an example number", path.display()))
+        })
+        .collect()
+}
+
+fn read_example(day: u8, number: u32, expected: Expected) -> Result<Example, String> {
+    let path = day_dir(day).join(format!("{number}.txt"));
+    let input = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(Example {
+        number,
+        input,
+        expected,
+    })
+}
+
+/// Every example of `day`, in order.
+pub fn load(day: u8) -> Result<Vec<Example>, String> {
+    let dir = day_dir(day);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let expected = load_expected(day)?;
+    let mut numbers = fs::read_dir(&dir)
+        .map_err(|e| format!("{}: {e}", dir.display()))?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "txt")
+                .then_some(())
+                .and_then(|_| path.file_stem()?.to_str()?.parse::<u32>().ok())
+        })
+        .collect::<Vec<_>>();
+    numbers.sort_unstable();
+    numbers
+        .into_iter()
+        .map(|n| read_example(day, n, expected.get(&n).copied().unwrap_or_default()))
+        .collect()
+}
+
+pub fn load_one(day: u8, number: u32) -> Result<Example, String> {
+    let expected = load_expected(day)?;
+    read_example(
+        day,
+        number,
+        expected.get(&number).copied().unwrap_or_default(),
+    )
+}
+
+/// One part of an example solved and compared with its expected answer.
+pub struct Checked {
+    pub example: u32,
+    pub part: u8,
+    pub answer: Answer,
+    pub expected: Answer,
+}
+
+/// Solves every part of `day`'s examples that has an expected answer.
+pub fn verify_day(day: &Day) -> Result<Vec<Checked>, String> {
+    let mut checked = Vec::new();
+    for example in load(day.number)? {
+        let parsed = (day.parse)(&example.input)
+            .map_err(|e| format!("day{:02} example {}: {e}", day.number, example.number))?;
+        for part in [1, 2] {
+            let Some(expected) = example.expected.part(part) else {
+                continue;
+            };
+            let answer = match part {
+                1 => parsed.part1(),
+                _ => parsed.part2(),
+            };
+            checked.push(Checked {
+                example: example.number,
+                part,
+                answer,
+                expected,
+            });
+        }
+    }
+    Ok(checked)
+}
+
+#[test]
+fn test_examples() {
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for day in advent_of_code_2023::days::DAYS {
+        for c in verify_day(day).unwrap() {
+            count += 1;
+            if c.answer != c.expected {
+                failures.push(format!(
+                    "day{:02} example {} part{}: {}, expected {}",
+                    day.number, c.example, c.part, c.answer, c.expected
+                ));
+            }
+        }
+    }
+    assert!(count > 0, "no examples found");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,286 @@
+use std::{fmt, str::FromStr};
+use strum::IntoEnumIterator;
+
+use crate::coords::{Coords, Direction4, Direction8, Turn};
+use crate::parse::ParseError;
+
+#[derive(PartialEq, Eq)]
+pub struct Map<T>(pub Vec<Vec<T>>);
+
+/// A tile of a `Map`, read from a character of the input and written back as the same one.
+pub trait Tile: TryFrom<char> {
+    /// What a character of the input can be, for errors: "a pipe `|`, `-`, ..."
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self> {
+        Self::try_from(c).ok()
+    }
+
+    fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+    const EXPECTED: &'static str = "a character";
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Reads a tile per character, every line as wide as the first one.
+impl<T: Tile> FromStr for Map<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = Vec::new();
+        for (i, l) in s.lines().enumerate() {
+            let tiles = l
+                .char_indices()
+                .map(|(at, c)| {
+                    T::try_from(c).map_err(|_| {
+                        ParseError::at(l, &l[at..at + c.len_utf8()], T::EXPECTED).on_line(i + 1)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let width = lines.first().map_or(tiles.len(), Vec::len);
+            let expected = || format!("{width} tiles, as on line 1");
+            if let Some((at, _)) = l.char_indices().nth(width) {
+                return Err(ParseError::at(l, &l[at..], expected()).on_line(i + 1));
+            }
+            if tiles.len() < width {
+                return Err(ParseError::after(l, l, expected()).on_line(i + 1));
+            }
+            lines.push(tiles);
+        }
+        Ok(Map(lines))
+    }
+}
+
+/// The map as it was read: a line of characters per line of tiles, each ending with a newline.
+impl<T: Tile> fmt::Display for Map<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.0.iter() {
+            for tile in line {
+                write!(f, "{}", tile.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Map<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f)?;
+        for line in self.0.iter() {
+            for c in line {
+                write!(f, "{:?}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Map<T> {
+    pub fn get(&self, coords: Coords) -> Option<&T> {
+        self.0
+            .get(usize::try_from(coords.line).ok()?)?
+            .get(usize::try_from(coords.col).ok()?)
+    }
+
+    pub fn get_mut(&mut self, coords: Coords) -> Option<&mut T> {
+        self.0
+            .get_mut(usize::try_from(coords.line).ok()?)?
+            .get_mut(usize::try_from(coords.col).ok()?)
+    }
+
+    /// Puts `value` at `coords`, returning the tile it replaces, or `None` outside the map.
+    pub fn set(&mut self, coords: Coords, value: T) -> Option<T> {
+        self.get_mut(coords)
+            .map(|tile| std::mem::replace(tile, value))
+    }
+
+    /// Number of columns, that of the first line.
+    pub fn width(&self) -> usize {
+        self.0.first().map_or(0, Vec::len)
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn contains(&self, coords: Coords) -> bool {
+        self.get(coords).is_some()
+    }
+
+    /// Every tile with its coordinates, line by line.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (Coords, &T)> {
+        self.0.iter().enumerate().flat_map(|(line, tiles)| {
+            tiles
+                .iter()
+                .enumerate()
+                .map(move |(col, tile)| (Coords::from((line, col)), tile))
+        })
+    }
+
+    fn around(
+        &self,
+        coords: Coords,
+        offsets: impl Iterator<Item = Coords>,
+    ) -> impl Iterator<Item = (Coords, &T)> {
+        offsets.filter_map(move |offset| {
+            let next = coords + offset;
+            Some((next, self.get(next)?))
+        })
+    }
+
+    /// The tiles up, right, down and left of `coords`, leaving out those outside the map.
+    pub fn neighbors4(&self, coords: Coords) -> impl Iterator<Item = (Coords, &T)> {
+        self.around(coords, Direction4::iter().map(Direction4::offset))
+    }
+
+    /// The 8 tiles around `coords` clockwise from up, leaving out those outside the map.
+    pub fn neighbors8(&self, coords: Coords) -> impl Iterator<Item = (Coords, &T)> {
+        self.around(coords, Direction8::iter().map(Direction8::offset))
+    }
+
+    /// The first tile, line by line, that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(Coords, &T)> {
+        self.iter_coords().find(|(_, tile)| predicate(tile))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coords> {
+        self.find(predicate).map(|(coords, _)| coords)
+    }
+
+    pub fn row(&self, line: usize) -> Option<&[T]> {
+        self.0.get(line).map(Vec::as_slice)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.0.iter().map(Vec::as_slice)
+    }
+
+    /// The tiles of column `col`, top to bottom.
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.0.iter().filter_map(move |tiles| tiles.get(col))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width()).map(|col| self.col(col))
+    }
+}
+
+impl<T: Clone> Map<T> {
+    /// Lines become columns: the tile at `line`, `col` moves to `col`, `line`.
+    pub fn transpose(&self) -> Map<T> {
+        Map(self.cols().map(|col| col.cloned().collect()).collect())
+    }
+
+    /// The map turned a quarter to the left or to the right.
+    pub fn rotate(&self, turn: Turn) -> Map<T> {
+        match turn {
+            Turn::Right => self.flip_vertical().transpose(),
+            Turn::Left => self.transpose().flip_vertical(),
+        }
+    }
+
+    /// The lines in reverse order, top and bottom swapped.
+    pub fn flip_vertical(&self) -> Map<T> {
+        Map(self.0.iter().rev().cloned().collect())
+    }
+
+    /// Every line reversed, left and right swapped.
+    pub fn flip_horizontal(&self) -> Map<T> {
+        Map(self
+            .0
+            .iter()
+            .map(|tiles| tiles.iter().rev().cloned().collect())
+            .collect())
+    }
+}
+
+#[test]
+fn test_map() {
+    let mut map: Map<char> = Map(vec!["abc".chars().collect(), "def".chars().collect()]);
+    assert_eq!((map.width(), map.height()), (3, 2));
+    assert_eq!(map.set(Coords::from((1, 2)), 'F'), Some('f'));
+    assert_eq!(map.set(Coords::from((2, 0)), 'x'), None);
+    assert_eq!(map.position(|&c| c == 'F'), Some(Coords::from((1, 2))));
+    assert_eq!(map.iter_coords().nth(4), Some((Coords::from((1, 1)), &'e')));
+    assert_eq!(
+        map.neighbors4(Coords::from((0, 0)))
+            .map(|(_, &c)| c)
+            .collect::<String>(),
+        "bd"
+    );
+    assert_eq!(
+        map.neighbors8(Coords::from((0, 1)))
+            .map(|(_, &c)| c)
+            .collect::<String>(),
+        "cFeda"
+    );
+    assert_eq!(map.col(1).collect::<String>(), "be");
+    assert_eq!(map.row(1), Some(&['d', 'e', 'F'][..]));
+
+    let show = |m: &Map<char>| {
+        m.rows()
+            .map(|r| r.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+    assert_eq!(show(&map.transpose()), "ad/be/cF");
+    assert_eq!(show(&map.rotate(Turn::Right)), "da/eb/Fc");
+    assert_eq!(show(&map.rotate(Turn::Left)), "cF/be/ad");
+    assert_eq!(show(&map.flip_vertical()), "deF/abc");
+    assert_eq!(show(&map.flip_horizontal()), "cba/Fed");
+    assert_eq!(map.to_string(), "abc\ndeF\n");
+}
+
+#[test]
+fn test_parse_errors() {
+    #[derive(Debug)]
+    struct Bit(bool);
+
+    impl TryFrom<char> for Bit {
+        type Error = ();
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '0' | '1' => Ok(Bit(value == '1')),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl Tile for Bit {
+        const EXPECTED: &'static str = "a bit";
+
+        fn to_char(&self) -> char {
+            if self.0 {
+                '1'
+            } else {
+                '0'
+            }
+        }
+    }
+
+    let map = "01\n10\n".parse::<Map<Bit>>().unwrap();
+    assert_eq!(map.to_string(), "01\n10\n");
+    let err = "01\n1é\n".parse::<Map<Bit>>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 2: expected a bit, found `é`"
+    );
+    let err = "01\n100\n".parse::<Map<Bit>>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: expected 2 tiles, as on line 1, found `0`"
+    );
+    let err = "01\n10\n1\n".parse::<Map<Bit>>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 3, column 2: expected 2 tiles, as on line 1, found nothing"
+    );
+}
@@ -0,0 +1,15 @@
+//! Advent of Code 2023 solutions, and the helpers shared by the days.
+
+pub mod algo;
+pub mod coords;
+pub mod days;
+pub mod grid;
+pub mod math;
+pub mod observe;
+pub mod parse;
+pub mod pathfind;
+pub mod render;
+pub mod rng;
+pub mod solution;
+pub mod sparse;
+pub mod trace;
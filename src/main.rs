@@ -0,0 +1,672 @@
+use std::{
+    borrow::Cow,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::Instant,
+};
+
+use clap::{Parser, Subcommand};
+
+#[cfg(feature = "alloc-stats")]
+mod alloc;
+mod animate;
+mod answers;
+mod bench;
+mod client;
+mod differential;
+mod examples;
+mod report;
+mod scaffold;
+
+use advent_of_code_2023::days::{self, Day};
+use advent_of_code_2023::rng::Rng;
+use advent_of_code_2023::trace;
+use answers::{AnswerStore, Verdict};
+use bench::BenchOptions;
+use client::{Client, Config, Outcome};
+use examples::Expected;
+use report::{Entry, Format};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print what the days report while solving, `-vv` for every step
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, or every registered day
+    Run {
+        /// Day number, or `all`
+        day: DaySelection,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file, or `-` for stdin, instead of the embedded one
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Solve `examples/dayNN/N.txt` instead of the puzzle input
+        #[arg(long, value_name = "N", conflicts_with = "input")]
+        example: Option<u32>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Solve this many days at once, then print a summary checked against `answers.toml`
+        #[arg(short, long, value_name = "N", conflicts_with_all = ["input", "example"],
+              value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+    },
+    /// Time a day's parse and parts over many iterations
+    Bench {
+        day: u8,
+        /// Untimed iterations run first
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed iterations
+        #[arg(short = 'n', long, default_value_t = 20, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Read the puzzle input from this file, or `-` for stdin, instead of the embedded one
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Baseline file, defaults to `target/aoc-bench/dayNN.json`
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+        /// Save these results as the new baseline
+        #[arg(long)]
+        save_baseline: bool,
+        /// Median slowdown, in percent, flagged as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check answers against the accepted ones
+    Verify {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Answers store, defaults to `answers.toml`
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+        /// Check the examples in `examples/` instead of the puzzle inputs
+        #[arg(long, conflicts_with = "answers")]
+        examples: bool,
+    },
+    /// Solve one part of a day and submit the answer, recording what the site says
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answers store to check and update, defaults to `answers.toml`
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+        /// Config file with `session` and `base_url`, defaults to `aoc.toml`
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+    },
+    /// Print a random valid input for a day
+    Gen {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Number of lines, or width of grids
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+    },
+    /// Compare days with their brute-force reference on generated inputs
+    Diff {
+        /// Day number, or `all` for every day with a reference
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Only compare this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Number of inputs, generated from seeds 0, 1, ...
+        #[arg(long, default_value_t = 100)]
+        seeds: u64,
+        /// Size of the inputs, kept small for the references
+        #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+    },
+    /// Draw a grid day's input, with what its solution finds in it
+    Render {
+        day: u8,
+        /// Image to write, as `.png`, `.ppm` or `.svg`
+        output: PathBuf,
+        /// Read the puzzle input from this file, or `-` for stdin, instead of the embedded one
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Side of a tile, in pixels
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u64).range(1..))]
+        scale: u64,
+    },
+    /// Watch a search over a grid day's input, step by step
+    Animate {
+        day: u8,
+        /// Which search, defaults to the day's first one
+        #[arg(long)]
+        search: Option<String>,
+        /// Read the puzzle input from this file instead of the embedded one
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Rounds shown per second
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+    },
+    /// Create and register a new day from a template
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Use the template for grid puzzles, parsed into a `Map`
+        #[arg(long)]
+        grid: bool,
+    },
+    /// Count allocations of a day's parse and parts (needs the `alloc-stats` feature)
+    Alloc {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+    },
+    /// Download a day's puzzle input, unless it is already there
+    Fetch {
+        day: u8,
+        /// Config file with `session` and `base_url`, defaults to `aoc.toml`
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    One(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(DaySelection::All),
+            n => n
+                .parse()
+                .map(DaySelection::One)
+                .map_err(|_| format!("`{n}` is neither a day number nor `all`")),
+        }
+    }
+}
+
+impl DaySelection {
+    fn days(self) -> Result<Vec<&'static Day>, String> {
+        match self {
+            DaySelection::All => Ok(days::DAYS.iter().collect()),
+            DaySelection::One(n) => days::get(n)
+                .map(|d| vec![d])
+                .ok_or_else(|| format!("day {n} is not registered")),
+        }
+    }
+}
+
+fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+fn load_input(day: &Day, path: Option<&Path>) -> Result<Cow<'static, str>, String> {
+    match path {
+        None => Ok(Cow::Borrowed(day.input)),
+        Some(path) => read_input(path)
+            .map(Cow::Owned)
+            .map_err(|e| format!("can't read `{}`: {e}", path.display())),
+    }
+}
+
+fn run_command(
+    day: DaySelection,
+    part: Option<u8>,
+    input: Option<&Path>,
+    example: Option<u32>,
+    format: Format,
+    jobs: Option<u64>,
+) -> Result<(), String> {
+    let days = day.days()?;
+    if let Some(jobs) = jobs {
+        return run_parallel(&days, part, format, jobs as usize);
+    }
+    if (input.is_some() || example.is_some()) && days.len() > 1 {
+        return Err("--input and --example need a single day".to_owned());
+    }
+    if format == Format::Markdown {
+        println!("{}", Entry::MARKDOWN_HEADER);
+    }
+    let mut entries = Vec::new();
+    for d in days {
+        let solved = match example {
+            Some(n) => {
+                let example = examples::load_one(d.number, n)?;
+                // Examples often only make sense for one of the parts
+                let parts = report::parts(part)
+                    .into_iter()
+                    .filter(|&p| example.expected.part(p).is_some())
+                    .collect::<Vec<_>>();
+                if parts.is_empty() {
+                    return Err(format!(
+                        "day{:02} example {n} has no expected answer{}",
+                        d.number,
+                        part.map_or(String::new(), |p| format!(" for part {p}"))
+                    ));
+                }
+                report::solve(d, &example.input, &parts, example.expected)
+            }
+            None => report::solve(
+                d,
+                &load_input(d, input)?,
+                &report::parts(part),
+                Expected::default(),
+            ),
+        };
+        for entry in &solved {
+            match format {
+                Format::Text => println!("{}", entry.text()),
+                Format::Markdown => println!("{}", entry.markdown_row()),
+                Format::Json => {}
+            }
+        }
+        entries.extend(solved);
+    }
+    if format == Format::Json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?
+        );
+    }
+    let failures = entries.iter().filter(|e| e.failed()).count();
+    if failures > 0 {
+        return Err(format!("{failures} parts failed"));
+    }
+    Ok(())
+}
+
+fn run_parallel(
+    days: &[&Day],
+    part: Option<u8>,
+    format: Format,
+    jobs: usize,
+) -> Result<(), String> {
+    let store = AnswerStore::load(&answers::default_path())?;
+    let cpu_start = report::cpu_time();
+    let start = Instant::now();
+    let mut entries = report::solve_parallel(days, part, jobs)?;
+    let wall = start.elapsed();
+    let cpu = report::cpu_time()
+        .zip(cpu_start)
+        .map(|(end, start)| end - start);
+
+    for entry in &mut entries {
+        entry.verdict = Some(entry.status(&store).0);
+    }
+    match format {
+        Format::Text => report::print_summary(&entries, &store),
+        Format::Markdown => {
+            println!("{}", Entry::MARKDOWN_HEADER);
+            for entry in &entries {
+                println!("{}", entry.markdown_row());
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?
+        ),
+    }
+    let cpu = cpu.map_or("unknown".to_owned(), |c| format!("{c:.1?}"));
+    let totals = format!("total wall-clock {wall:.1?}, CPU {cpu} (--jobs {jobs})");
+    match format {
+        Format::Text => println!("{totals}"),
+        _ => eprintln!("{totals}"),
+    }
+    let failures = entries.iter().filter(|e| e.status(&store).1).count();
+    if failures > 0 {
+        return Err(format!("{failures} parts failed or were wrong"));
+    }
+    Ok(())
+}
+
+fn bench_command(day: u8, input: Option<&Path>, opts: &BenchOptions) -> Result<(), String> {
+    let day = days::get(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    let input = load_input(day, input)?;
+    if bench::bench(day, &input, opts)? {
+        return Err("performance regressed against the baseline".to_owned());
+    }
+    Ok(())
+}
+
+fn verify_examples(day: DaySelection) -> Result<(), String> {
+    let mut failures = 0;
+    for d in day.days()? {
+        for c in examples::verify_day(d)? {
+            if c.answer == c.expected {
+                println!(
+                    "day{:02} example {} part{} {} ok",
+                    d.number, c.example, c.part, c.answer
+                );
+            } else {
+                println!(
+                    "day{:02} example {} part{} {} WRONG, expected {}",
+                    d.number, c.example, c.part, c.answer, c.expected
+                );
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{failures} wrong answers"));
+    }
+    Ok(())
+}
+
+fn verify_command(day: DaySelection, answers: Option<PathBuf>) -> Result<(), String> {
+    let store = AnswerStore::load(&answers.unwrap_or_else(answers::default_path))?;
+    let mut failures = 0;
+    for d in day.days()? {
+        for (part, (answer, verdict)) in answers::verify_day(d, &store)?.iter().enumerate() {
+            println!("day{:02} part{} {answer} {verdict}", d.number, part + 1);
+            if verdict.is_failure() {
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{failures} wrong answers"));
+    }
+    Ok(())
+}
+
+fn fetch_command(day: u8, config: Option<PathBuf>) -> Result<(), String> {
+    let config = Config::load(&config.unwrap_or_else(Config::default_path))?;
+    let client = Client::from_config(&config)?;
+    let (path, downloaded) = client::fetch(&client, &client::default_inputs_dir(), day)?;
+    if downloaded {
+        println!("day{day:02} input saved to {}", path.display());
+    } else {
+        println!("day{day:02} input already in {}", path.display());
+    }
+    Ok(())
+}
+
+fn submit_command(
+    day: u8,
+    part: u8,
+    answers: Option<PathBuf>,
+    config: Option<PathBuf>,
+) -> Result<(), String> {
+    let d = days::get(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    let parsed = (d.parse)(d.input).map_err(|e| format!("day{day:02}: {e}"))?;
+    let answer = match part {
+        1 => parsed.part1(),
+        _ => parsed.part2(),
+    };
+    let path = answers.unwrap_or_else(answers::default_path);
+    let mut store = AnswerStore::load(&path)?;
+    match store.check(day, part, answer) {
+        Verdict::Unknown => {}
+        Verdict::Correct => {
+            println!("day{day:02} part{part} {answer} already accepted");
+            return Ok(());
+        }
+        verdict => {
+            return Err(format!(
+                "day{day:02} part{part} {answer} {verdict}, not submitted"
+            ))
+        }
+    }
+
+    let config = Config::load(&config.unwrap_or_else(Config::default_path))?;
+    let outcome = Client::from_config(&config)?.submit(day, part, answer)?;
+    println!("day{day:02} part{part} {answer} {outcome}");
+    if store.record(day, part, answer, &outcome) {
+        store.save(&path)?;
+    }
+    match outcome {
+        Outcome::Correct | Outcome::AlreadySolved => Ok(()),
+        _ => Err(format!("day{day:02} part{part} not accepted")),
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+fn alloc_command(day: DaySelection) -> Result<(), String> {
+    println!(
+        "{:<5} {:<6} {:>10} {:>12} {:>12}",
+        "day", "step", "allocs", "bytes", "peak"
+    );
+    for d in day.days()? {
+        let stats = alloc::count(d)?;
+        for (step, s) in ["parse", "part1", "part2"].iter().zip(stats) {
+            println!(
+                "day{:02} {step:<6} {:>10} {:>12} {:>12}",
+                d.number,
+                s.allocs,
+                alloc::bytes(s.bytes),
+                alloc::bytes(s.peak_bytes)
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+fn alloc_command(_day: DaySelection) -> Result<(), String> {
+    Err("counting allocations needs `--features alloc-stats`".to_owned())
+}
+
+fn gen_command(day: u8, seed: u64, size: usize) -> Result<(), String> {
+    let day = days::get(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    print!("{}", (day.generate)(&mut Rng::new(seed), size));
+    Ok(())
+}
+
+fn diff_command(
+    day: DaySelection,
+    part: Option<u8>,
+    seeds: u64,
+    size: usize,
+) -> Result<(), String> {
+    let single = matches!(day, DaySelection::One(_));
+    let mut mismatches = 0;
+    let _quiet = differential::QuietPanics::new();
+    for d in day.days()? {
+        let Some(reference) = &d.reference else {
+            if single {
+                return Err(format!("day {} has no reference solver", d.number));
+            }
+            continue;
+        };
+        if let Some(p) = part.filter(|p| single && !reference.parts.contains(p)) {
+            return Err(format!("day {}'s reference can't solve part {p}", d.number));
+        }
+        for &p in reference
+            .parts
+            .iter()
+            .filter(|&&p| part.is_none_or(|part| part == p))
+        {
+            match differential::check(d, p, 0..seeds, size)? {
+                None => println!("day{:02} part{p} agrees on {seeds} inputs", d.number),
+                Some(m) => {
+                    mismatches += 1;
+                    let show = |o: &differential::Outcome| match o {
+                        Ok(answer) => answer.to_string(),
+                        Err(e) => format!("panic ({e})"),
+                    };
+                    println!(
+                        "day{:02} part{} differs on seed {}: {} vs reference {}, shrunk input:\n{}",
+                        d.number,
+                        m.part,
+                        m.seed,
+                        show(&m.answer),
+                        show(&m.reference),
+                        m.input
+                    );
+                }
+            }
+        }
+    }
+    if mismatches > 0 {
+        return Err(format!("{mismatches} parts differ from their reference"));
+    }
+    Ok(())
+}
+
+fn render_command(
+    day: u8,
+    output: &Path,
+    input: Option<&Path>,
+    scale: usize,
+) -> Result<(), String> {
+    let d = days::get(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    let render = d
+        .render
+        .ok_or_else(|| format!("day {day} has nothing to render"))?;
+    let mut image = render(&load_input(d, input)?).map_err(|e| format!("day{day:02}: {e}"))?;
+    image.scale = scale;
+    image.save(output)?;
+    println!(
+        "day{day:02} drawn in {}, {}x{} tiles",
+        output.display(),
+        image.width(),
+        image.height()
+    );
+    Ok(())
+}
+
+fn animate_command(
+    day: u8,
+    search: Option<&str>,
+    input: Option<&Path>,
+    fps: f64,
+) -> Result<(), String> {
+    let d = days::get(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    // Keys are read from stdin while the animation plays
+    if input == Some(Path::new("-")) {
+        return Err("animate reads keys from stdin, give --input a file instead of `-`".to_owned());
+    }
+    let names = || {
+        d.searches
+            .iter()
+            .map(|s| s.name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let search = match search {
+        None => d
+            .searches
+            .first()
+            .ok_or_else(|| format!("day {day} has no search to watch"))?,
+        Some(name) => d
+            .searches
+            .iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| format!("day {day} has no `{name}` search, only: {}", names()))?,
+    };
+    if fps.is_nan() || fps <= 0.0 {
+        return Err("--fps must be positive".to_owned());
+    }
+    let input = load_input(d, input)?;
+    let mut animation = animate::Animation::new(&input, search.name, fps);
+    animation.start();
+    (search.run)(&input, &mut |step| animation.step(step))
+        .map_err(|e| format!("day{day:02}: {e}"))?;
+    animation.finish();
+    Ok(())
+}
+
+fn new_command(day: u8, grid: bool) -> Result<(), String> {
+    for path in scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day, grid)? {
+        println!("created {path}");
+    }
+    println!("registered day{day:02}, `aoc fetch {day}` downloads its input");
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    trace::set_level(cli.verbose);
+    let res = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            example,
+            format,
+            jobs,
+        } => run_command(day, part, input.as_deref(), example, format, jobs),
+        Command::Bench {
+            day,
+            warmup,
+            iterations,
+            input,
+            baseline,
+            save_baseline,
+            threshold,
+        } => bench_command(
+            day,
+            input.as_deref(),
+            &BenchOptions {
+                warmup,
+                iterations: iterations as usize,
+                threshold,
+                baseline,
+                save_baseline,
+            },
+        ),
+        Command::Verify {
+            day,
+            examples: true,
+            ..
+        } => verify_examples(day),
+        Command::Verify { day, answers, .. } => verify_command(day, answers),
+        Command::Submit {
+            day,
+            part,
+            answers,
+            config,
+        } => submit_command(day, part, answers, config),
+        Command::Alloc { day } => alloc_command(day),
+        Command::Gen { day, seed, size } => gen_command(day, seed, size as usize),
+        Command::Diff {
+            day,
+            part,
+            seeds,
+            size,
+        } => diff_command(day, part, seeds, size as usize),
+        Command::Render {
+            day,
+            output,
+            input,
+            scale,
+        } => render_command(day, &output, input.as_deref(), scale as usize),
+        Command::Animate {
+            day,
+            search,
+            input,
+            fps,
+        } => animate_command(day, search.as_deref(), input.as_deref(), fps),
+        Command::New { day, grid } => new_command(day, grid),
+        Command::Fetch { day, config } => fetch_command(day, config),
+    };
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
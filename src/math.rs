@@ -0,0 +1,30 @@
+use num::integer::lcm;
+
+/// Least common multiple of all of `values`, `None` if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().reduce(lcm)
+}
+
+/// Real roots of `a * x^2 + b * x + c`, smallest first, `None` if there are none.
+pub fn quadratic_roots(a: f64, b: f64, c: f64) -> Option<(f64, f64)> {
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let (x1, x2) = (
+        (-b - discriminant.sqrt()) / (2.0 * a),
+        (-b + discriminant.sqrt()) / (2.0 * a),
+    );
+    Some((x1.min(x2), x1.max(x2)))
+}
+
+#[test]
+fn test_quadratic_roots() {
+    assert_eq!(quadratic_roots(1.0, -3.0, 2.0), Some((1.0, 2.0)));
+    assert_eq!(
+        quadratic_roots(-1.0, 7.0, -9.0).map(|(a, _)| a > 1.6),
+        Some(true)
+    );
+    assert_eq!(quadratic_roots(1.0, 0.0, 1.0), None);
+    assert_eq!(lcm_all([4, 6, 10]), Some(60));
+}
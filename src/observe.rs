@@ -0,0 +1,24 @@
+use crate::coords::Coords;
+use crate::parse::ParseError;
+
+/// Something a search just did to a cell of a grid.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Step {
+    /// The cell is reached, `depth` steps away from where the search started
+    Visit { at: Coords, depth: i64 },
+    /// The cell is queued, to be visited later
+    Queue(Coords),
+    /// The cell is found to belong to what is searched for, like the inside of a loop
+    Mark(Coords),
+    /// A round is over: a level of a BFS, a line of a scan
+    Round,
+}
+
+/// Called on every step of a search.
+pub type Observer<'a> = dyn FnMut(Step) + 'a;
+
+/// A search over a day's grid that reports its steps, to be watched.
+pub struct Search {
+    pub name: &'static str,
+    pub run: fn(&str, &mut Observer) -> Result<(), ParseError>,
+}
@@ -0,0 +1,101 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Where and why some input could not be parsed.
+///
+/// Line and column are 1-based, and the column counts characters. Parsers of a
+/// single line leave `line` at 0 and let [`parse_lines`] fill it in.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+/// 1-based column of `token` inside `s`, `token` being a slice of `s`.
+fn column_of(s: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(s.as_ptr() as usize)
+        .filter(|&o| o <= s.len())
+        .unwrap_or(s.len());
+    s[..offset].chars().count() + 1
+}
+
+impl ParseError {
+    /// `token`, a slice of `s`, is not what was expected.
+    pub fn at(s: &str, token: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: column_of(s, token),
+            token: token.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Something was expected right after `part`, a slice of `s`, but nothing was found.
+    pub fn after(s: &str, part: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: column_of(s, part) + part.chars().count(),
+            token: String::new(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Moves an error found while parsing `inner`, a slice of `s`, to its position in `s`.
+    pub fn within(mut self, s: &str, inner: &str) -> Self {
+        self.column += column_of(s, inner) - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, ", self.line)?;
+        }
+        write!(f, "column {}: expected {}, ", self.column, self.expected)?;
+        if self.token.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.token)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `s`, reporting its position in `s` on failure.
+pub fn parse_token<T: FromStr>(s: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(s, token, expected))
+}
+
+/// Parses every line of `input`, reporting the line number on failure.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| l.parse().map_err(|e: ParseError| e.on_line(i + 1)))
+        .collect()
+}
+
+#[test]
+fn test_column() {
+    let s = "Game 1: 3 blue";
+    let err = parse_token::<u32>(s, &s[10..], "a number").unwrap_err();
+    assert_eq!(err.column, 11);
+    assert_eq!(err.token, "blue");
+    let err = ParseError::after(s, &s[..4], "a game id");
+    assert_eq!(err.column, 5);
+    assert_eq!(
+        err.on_line(3).to_string(),
+        "line 3, column 5: expected a game id, found nothing"
+    );
+}
@@ -0,0 +1,294 @@
+//! Shortest paths over any graph given by a neighbour closure: plain steps (BFS), costed
+//! steps (Dijkstra), or costed steps guided by an estimate of what is left (A*).
+//!
+//! States can be anything hashable, like `Coords` or `(Coords, Direction, steps)`.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::coords::Coords;
+use crate::grid::Map;
+
+/// What a search found: the distance of every state it settled, and how it got there.
+pub struct Paths<S> {
+    distances: HashMap<S, i64>,
+    previous: HashMap<S, S>,
+    /// The first target reached, the closest one
+    pub target: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    fn new() -> Self {
+        Paths {
+            distances: HashMap::new(),
+            previous: HashMap::new(),
+            target: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<i64> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, i64> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<S, i64> {
+        self.distances
+    }
+
+    /// The states from the closest source to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.previous.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Distance and path of the target reached, if any.
+    pub fn to_target(&self) -> Option<(i64, Vec<S>)> {
+        let target = self.target.as_ref()?;
+        Some((self.distance(target)?, self.path(target)?))
+    }
+}
+
+/// Breadth-first search from every state of `sources` at once, each step costing 1.
+///
+/// Stops at the first state `is_target` accepts; `|_| false` maps everything reachable.
+pub fn bfs<S, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if !paths.distances.contains_key(&source) {
+            paths.distances.insert(source.clone(), 0);
+            queue.push_back(source);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        if is_target(&state) {
+            paths.target = Some(state);
+            break;
+        }
+        let distance = paths.distances[&state];
+        for next in neighbors(&state) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), distance + 1);
+                paths.previous.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// A state waiting in the heap, ordered by priority only.
+struct Queued<S> {
+    priority: i64,
+    distance: i64,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// Dijkstra's search from every state of `sources`, `neighbors` giving the cost, never
+/// negative, of each step.
+pub fn dijkstra<S, I>(
+    sources: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    is_target: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, i64)>,
+{
+    astar(sources, neighbors, |_| 0, is_target)
+}
+
+/// A* search: Dijkstra's, trying first the states `estimate` puts closer to a target.
+///
+/// The estimate must never exceed the real cost left, nor drop by more than the cost of a
+/// step, for distances to be the shortest.
+pub fn astar<S, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut estimate: impl FnMut(&S) -> i64,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, i64)>,
+{
+    let mut paths = Paths::new();
+    let mut best = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for source in sources {
+        best.insert(source.clone(), 0);
+        heap.push(Reverse(Queued {
+            priority: estimate(&source),
+            distance: 0,
+            state: source,
+        }));
+    }
+    while let Some(Reverse(Queued {
+        distance, state, ..
+    })) = heap.pop()
+    {
+        if paths.distances.contains_key(&state) || best[&state] < distance {
+            continue;
+        }
+        paths.distances.insert(state.clone(), distance);
+        if is_target(&state) {
+            paths.target = Some(state);
+            break;
+        }
+        for (next, cost) in neighbors(&state) {
+            let next_distance = distance + cost;
+            if best.get(&next).is_some_and(|&d| d <= next_distance) {
+                continue;
+            }
+            best.insert(next.clone(), next_distance);
+            paths.previous.insert(next.clone(), state.clone());
+            heap.push(Reverse(Queued {
+                priority: next_distance + estimate(&next),
+                distance: next_distance,
+                state: next,
+            }));
+        }
+    }
+    // States seen but never settled have no sure distance
+    paths
+        .previous
+        .retain(|state, _| paths.distances.contains_key(state));
+    paths
+}
+
+impl<T> Map<T> {
+    /// Steps from any of `sources` to every tile reachable through the 4 neighbours,
+    /// moving from a tile to the next only where `can_step` allows it.
+    pub fn distances(
+        &self,
+        sources: impl IntoIterator<Item = Coords>,
+        can_step: impl Fn(&T, &T) -> bool,
+    ) -> Paths<Coords> {
+        bfs(
+            sources,
+            |&here| {
+                let tile = self.get(here);
+                self.neighbors4(here)
+                    .filter(|(_, next)| tile.is_some_and(|tile| can_step(tile, next)))
+                    .map(|(next, _)| next)
+                    .collect::<Vec<_>>()
+            },
+            |_| false,
+        )
+    }
+}
+
+#[cfg(test)]
+fn maze() -> Map<char> {
+    "#.#####\n#.....#\n#.###.#\n#...#.#\n###.#..\n"
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn test_bfs() {
+    let maze = maze();
+    let at = |line, col| Coords { line, col };
+    let open = |_: &char, next: &char| *next == '.';
+    let paths = maze.distances([at(0, 1)], open);
+    assert_eq!(paths.distance(&at(4, 6)), Some(9));
+    assert_eq!(paths.distance(&at(4, 3)), Some(6));
+    assert_eq!(
+        paths.path(&at(2, 1)),
+        Some(vec![at(0, 1), at(1, 1), at(2, 1)])
+    );
+    // From both ends, the closest one counts
+    let paths = maze.distances([at(0, 1), at(4, 6)], open);
+    assert_eq!(paths.distance(&at(1, 5)), Some(4));
+    assert_eq!(paths.path(&at(2, 5)).unwrap()[0], at(4, 6));
+
+    let paths = bfs(
+        [at(0, 1)],
+        |&c| {
+            maze.neighbors4(c)
+                .filter(|(_, &t)| t == '.')
+                .map(|(n, _)| n)
+                .collect::<Vec<_>>()
+        },
+        |&c| c.line == 4,
+    );
+    assert_eq!(paths.to_target().map(|(d, p)| (d, p.len())), Some((6, 7)));
+}
+
+#[test]
+fn test_dijkstra_astar() {
+    // Digits are the cost of entering a tile, moving at most 2 tiles straight in a row
+    let map: Map<char> = "1199\n9199\n9111\n9991\n".parse().unwrap();
+    let at = |line, col| Coords { line, col };
+    let goal = at(3, 3);
+    type State = (Coords, (i64, i64), u8);
+    let map = &map;
+    let neighbors = |&(here, dir, straight): &State| {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter(move |&d| d != (-dir.0, -dir.1) && (d != dir || straight < 2))
+            .filter_map(move |d| {
+                let next = here
+                    + Coords {
+                        line: d.0,
+                        col: d.1,
+                    };
+                let cost = map.get(next)?.to_digit(10)? as i64;
+                let straight = if d == dir { straight + 1 } else { 1 };
+                Some(((next, d, straight), cost))
+            })
+            .collect::<Vec<_>>()
+    };
+    let start = (at(0, 0), (0, 0), 0);
+    let found = dijkstra([start], neighbors, |s| s.0 == goal);
+    let (cost, path) = found.to_target().unwrap();
+    assert_eq!(cost, 6);
+    assert_eq!(path.len(), 7);
+    let guided = astar(
+        [start],
+        neighbors,
+        |s| s.0.dist_square(&goal),
+        |s| s.0 == goal,
+    );
+    assert_eq!(guided.to_target().unwrap().0, 6);
+    assert!(guided.distances().len() <= found.distances().len());
+}
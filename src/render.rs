@@ -0,0 +1,215 @@
+use std::{fmt::Write as _, fs, path::Path};
+
+use crate::coords::Coords;
+use crate::grid::Map;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// How a cell is drawn: a filled square, with a glyph on top in SVG.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Cell {
+    pub fill: Rgb,
+    pub glyph: Option<char>,
+}
+
+impl From<Rgb> for Cell {
+    fn from(fill: Rgb) -> Self {
+        Cell { fill, glyph: None }
+    }
+}
+
+/// A map drawn cell by cell, ready to be saved as PPM, PNG or SVG.
+pub struct Image {
+    cells: Vec<Vec<Cell>>,
+    /// Side of a cell, in pixels
+    pub scale: usize,
+}
+
+impl<T> Map<T> {
+    /// Draws every tile through `paint`.
+    pub fn render(&self, paint: impl Fn(&T) -> Cell) -> Image {
+        Image {
+            cells: self
+                .0
+                .iter()
+                .map(|line| line.iter().map(&paint).collect())
+                .collect(),
+            scale: 8,
+        }
+    }
+}
+
+/// `from` moved towards `to` by `amount`, between 0 and 1.
+pub fn blend(from: Rgb, to: Rgb, amount: f64) -> Rgb {
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * amount).round() as u8;
+    [
+        mix(from[0], to[0]),
+        mix(from[1], to[1]),
+        mix(from[2], to[2]),
+    ]
+}
+
+/// Heatmap colour of `value` out of `max`, from blue through green to red.
+pub fn heat(value: i64, max: i64) -> Rgb {
+    let t = if max > 0 {
+        (value as f64 / max as f64).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    if t < 0.5 {
+        blend([40, 60, 220], [40, 200, 60], t * 2.0)
+    } else {
+        blend([40, 200, 60], [230, 40, 30], t * 2.0 - 1.0)
+    }
+}
+
+impl Image {
+    pub fn width(&self) -> usize {
+        self.cells.first().map_or(0, Vec::len)
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Paints a layer over the image: cells `paint` gives a colour to are blended
+    /// towards it by `opacity`, the others are left as they are.
+    pub fn layer(&mut self, opacity: f64, paint: impl Fn(Coords) -> Option<Rgb>) -> &mut Self {
+        for (line, cells) in self.cells.iter_mut().enumerate() {
+            for (col, cell) in cells.iter_mut().enumerate() {
+                let coords = Coords {
+                    line: line as i64,
+                    col: col as i64,
+                };
+                if let Some(color) = paint(coords) {
+                    cell.fill = blend(cell.fill, color, opacity);
+                }
+            }
+        }
+        self
+    }
+
+    fn pixels(&self) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.width() * self.height() * self.scale.pow(2) * 3);
+        for line in &self.cells {
+            for _ in 0..self.scale {
+                for cell in line {
+                    for _ in 0..self.scale {
+                        pixels.extend(cell.fill);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    /// Binary PPM (P6), glyphs left out.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!(
+            "P6\n{} {}\n255\n",
+            self.width() * self.scale,
+            self.height() * self.scale
+        )
+        .into_bytes();
+        ppm.extend(self.pixels());
+        ppm
+    }
+
+    /// PNG, glyphs left out.
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(
+            &mut png,
+            (self.width() * self.scale) as u32,
+            (self.height() * self.scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.pixels()))
+            .map_err(|e| e.to_string())?;
+        Ok(png)
+    }
+
+    /// SVG with a square per cell, and its glyph drawn in black or white over it.
+    pub fn to_svg(&self) -> String {
+        let s = self.scale;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             font-family=\"monospace\" font-size=\"{s}\" text-anchor=\"middle\" \
+             dominant-baseline=\"central\">\n",
+            self.width() * s,
+            self.height() * s
+        );
+        for (line, cells) in self.cells.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                let (x, y) = (col * s, line * s);
+                let [r, g, b] = cell.fill;
+                writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{s}\" height=\"{s}\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>"
+                )
+                .unwrap();
+                if let Some(glyph) = cell.glyph {
+                    let light = r as u32 + g as u32 + b as u32 > 384;
+                    let escaped = match glyph {
+                        '<' => "&lt;".to_owned(),
+                        '>' => "&gt;".to_owned(),
+                        '&' => "&amp;".to_owned(),
+                        c => c.to_string(),
+                    };
+                    writeln!(
+                        svg,
+                        "<text x=\"{}\" y=\"{}\" fill=\"{}\">{escaped}</text>",
+                        x as f64 + s as f64 / 2.0,
+                        y as f64 + s as f64 / 2.0,
+                        if light { "black" } else { "white" }
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Writes the image in the format of the extension of `path`: `ppm`, `png` or `svg`.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let data = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            Some("png") => self.to_png()?,
+            Some("svg") => self.to_svg().into_bytes(),
+            _ => {
+                return Err(format!(
+                    "`{}`: images are saved as .ppm, .png or .svg",
+                    path.display()
+                ))
+            }
+        };
+        fs::write(path, data).map_err(|e| format!("can't write `{}`: {e}", path.display()))
+    }
+}
+
+#[test]
+fn test_render() {
+    let map: Map<char> = Map(vec![vec!['#', '.'], vec!['.', '#']]);
+    let mut image = map.render(|&c| match c {
+        '#' => WHITE.into(),
+        _ => BLACK.into(),
+    });
+    image.scale = 1;
+    image.layer(0.5, |c| (c.line == 0).then_some(BLACK));
+    assert_eq!(
+        image.to_ppm(),
+        b"P6\n2 2\n255\n\x80\x80\x80\0\0\0\0\0\0\xff\xff\xff"
+    );
+    let png = image.to_png().unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+    assert_eq!(image.to_svg().matches("<rect").count(), 4);
+    assert_eq!(heat(0, 10), [40, 60, 220]);
+    assert_eq!(heat(10, 10), [230, 40, 30]);
+}
@@ -0,0 +1,281 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use clap::ValueEnum;
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::answers::AnswerStore;
+use crate::examples::Expected;
+use advent_of_code_2023::days::Day;
+use advent_of_code_2023::solution::Answer;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One `dayNN partN answer` line per part
+    Text,
+    /// A JSON array of entries
+    Json,
+    /// A Markdown table
+    Markdown,
+}
+
+/// The outcome of solving one part of a day.
+#[derive(Serialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Answer>,
+    /// Time spent parsing the input, shared by both parts
+    pub parse_ns: u64,
+    pub solve_ns: Option<u64>,
+    /// Why there is no answer: the input did not parse or the part panicked
+    pub error: Option<String>,
+    /// The status against answers.toml, when checked against it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+}
+
+pub fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(s) => *s,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(s) => s.to_string(),
+            Err(_) => "panicked".to_owned(),
+        },
+    }
+}
+
+/// Parts 1 and 2, or only `part`.
+pub fn parts(part: Option<u8>) -> Vec<u8> {
+    [1, 2]
+        .into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .collect()
+}
+
+/// Solves `parts` of `day` on `input`, timing each step.
+pub fn solve(day: &Day, input: &str, parts: &[u8], expected: Expected) -> Vec<Entry> {
+    let start = Instant::now();
+    let parsed = (day.parse)(input);
+    let parse_ns = start.elapsed().as_nanos() as u64;
+    parts
+        .iter()
+        .map(|&p| {
+            let mut entry = Entry {
+                day: day.number,
+                part: p,
+                answer: None,
+                expected: expected.part(p),
+                parse_ns,
+                solve_ns: None,
+                error: None,
+                verdict: None,
+            };
+            match &parsed {
+                Ok(parsed) => {
+                    let start = Instant::now();
+                    let answer = panic::catch_unwind(AssertUnwindSafe(|| match p {
+                        1 => parsed.part1(),
+                        _ => parsed.part2(),
+                    }));
+                    entry.solve_ns = Some(start.elapsed().as_nanos() as u64);
+                    match answer {
+                        Ok(answer) => entry.answer = Some(answer),
+                        Err(payload) => entry.error = Some(panic_message(payload)),
+                    }
+                }
+                Err(e) => entry.error = Some(e.to_string()),
+            }
+            entry
+        })
+        .collect()
+}
+
+/// Solves every day of `days` on its embedded input, `jobs` days at a time.
+///
+/// Entries come back in the order of `days`.
+pub fn solve_parallel(days: &[&Day], part: Option<u8>, jobs: usize) -> Result<Vec<Entry>, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(pool.install(|| {
+        days.par_iter()
+            .map(|d| solve(d, d.input, &parts(part), Expected::default()))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flatten()
+            .collect()
+    }))
+}
+
+/// User and system CPU time used by this process so far.
+#[cfg(unix)]
+pub fn cpu_time() -> Option<Duration> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: getrusage only writes into the struct it is given
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: getrusage succeeded, so it filled the struct
+    let usage = unsafe { usage.assume_init() };
+    let time = |t: libc::timeval| {
+        Duration::from_secs(t.tv_sec as u64) + Duration::from_micros(t.tv_usec as u64)
+    };
+    Some(time(usage.ru_utime) + time(usage.ru_stime))
+}
+
+#[cfg(not(unix))]
+pub fn cpu_time() -> Option<Duration> {
+    None
+}
+
+/// Prints `entries` as a table, checking answers against `store`.
+pub fn print_summary(entries: &[Entry], store: &AnswerStore) {
+    println!(
+        "{:<4} {:>4} {:>16} {:>10} {:>10}  status",
+        "day", "part", "answer", "parse", "solve"
+    );
+    for e in entries {
+        let (status, _) = e.status(store);
+        println!(
+            "{:<4} {:>4} {:>16} {:>10} {:>10}  {status}",
+            format!("{:02}", e.day),
+            e.part,
+            e.answer.map_or(String::new(), |a| a.to_string()),
+            duration(e.parse_ns),
+            e.solve_ns.map_or(String::new(), duration),
+        );
+    }
+}
+
+fn duration(ns: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns))
+}
+
+impl Entry {
+    /// The error or the verdict of `store` on the answer, and whether it is a failure.
+    pub fn status(&self, store: &AnswerStore) -> (String, bool) {
+        match (&self.error, self.answer) {
+            (Some(error), _) => (format!("error: {error}"), true),
+            (None, answer) => {
+                let verdict = store.check(self.day, self.part, answer.unwrap_or_default());
+                (verdict.to_string(), verdict.is_failure())
+            }
+        }
+    }
+
+    /// The answer expected instead of the one found, if they differ.
+    pub fn wrong(&self) -> Option<Answer> {
+        self.expected
+            .filter(|&e| self.error.is_none() && self.answer != Some(e))
+    }
+
+    /// Whether the part has an error or a wrong answer.
+    pub fn failed(&self) -> bool {
+        self.error.is_some() || self.wrong().is_some()
+    }
+
+    pub fn text(&self) -> String {
+        let name = format!("day{:02} part{}", self.day, self.part);
+        match (&self.error, self.answer, self.expected) {
+            (Some(e), _, _) => format!("{name} error: {e}"),
+            (None, Some(answer), Some(e)) if answer != e => {
+                format!("{name} {answer} WRONG, expected {e}")
+            }
+            (None, Some(answer), Some(e)) => format!("{name} {answer} (expected {e})"),
+            (None, answer, _) => format!("{name} {}", answer.unwrap_or_default()),
+        }
+    }
+
+    pub const MARKDOWN_HEADER: &'static str =
+        "| Day | Part | Answer | Parse | Solve | Status |\n|---:|---:|---:|---:|---:|---|";
+
+    pub fn markdown_row(&self) -> String {
+        format!(
+            "| {} | {} | {} | {} | {} | {} |",
+            self.day,
+            self.part,
+            self.answer.map_or(String::new(), |a| a.to_string()),
+            duration(self.parse_ns),
+            self.solve_ns.map_or(String::new(), duration),
+            match (&self.verdict, &self.error, self.wrong()) {
+                (Some(verdict), _, _) => verdict.replace('|', "\\|"),
+                (None, Some(e), _) => format!("error: {}", e.replace('|', "\\|")),
+                (None, None, Some(expected)) => format!("wrong, expected {expected}"),
+                (None, None, None) => "ok".to_owned(),
+            }
+        )
+    }
+}
+
+#[test]
+fn test_solve_parallel() {
+    let days = advent_of_code_2023::days::DAYS
+        .iter()
+        .take(4)
+        .collect::<Vec<_>>();
+    let entries = solve_parallel(&days, Some(1), 2).unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|e| (e.day, e.answer))
+            .collect::<Vec<_>>(),
+        [
+            (1, Some(54388)),
+            (2, Some(2476)),
+            (3, Some(550934)),
+            (4, Some(33950))
+        ]
+    );
+}
+
+#[test]
+fn test_solve_errors() {
+    let day = advent_of_code_2023::days::get(9).unwrap();
+    let entries = solve(day, "0 3 6\n1 x 3\n", &parts(None), Expected::default());
+    assert_eq!(entries.len(), 2);
+    assert!(entries
+        .iter()
+        .all(|e| e.answer.is_none() && e.solve_ns.is_none()));
+    assert_eq!(
+        entries[0].error.as_deref(),
+        Some("line 2, column 3: expected a number, found `x`")
+    );
+    assert!(entries[1].markdown_row().starts_with("| 9 | 2 |  | "));
+
+    let entries = solve(day, "0 3 6\n", &parts(Some(2)), Expected::default());
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].answer, Some(-3));
+    assert!(entries[0].markdown_row().ends_with(" | ok |"));
+
+    let expected = Expected {
+        part1: Some(10),
+        part2: Some(-3),
+    };
+    let entries = solve(day, "0 3 6\n", &[1, 2], expected);
+    assert!(entries[0].failed());
+    assert!(entries[0]
+        .markdown_row()
+        .ends_with(" | wrong, expected 10 |"));
+    assert_eq!(entries[0].text(), "day09 part1 9 WRONG, expected 10");
+    assert!(!entries[1].failed());
+}
+
+#[test]
+fn test_verdict() {
+    let store: AnswerStore = toml::from_str("[day09.part1]\nanswer = 10").unwrap();
+    let day = advent_of_code_2023::days::get(9).unwrap();
+    let mut entries = solve(day, "0 3 6\n", &[1], Expected::default());
+    entries[0].verdict = Some(entries[0].status(&store).0);
+    assert!(entries[0]
+        .markdown_row()
+        .ends_with(" | WRONG, expected 10 |"));
+    let json = serde_json::to_string(&entries[0]).unwrap();
+    assert!(json.ends_with(r#""error":null,"verdict":"WRONG, expected 10"}"#));
+}
@@ -0,0 +1,62 @@
+use std::ops::RangeInclusive;
+
+/// Small seedable random number generator (SplitMix64), for input generators.
+///
+/// The same seed always gives the same numbers, on every platform.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` being positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = (range.end() - range.start()) as u64 + 1;
+        range.start() + self.below(span) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut rng = Rng::new(42);
+    let first = (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>();
+    let mut rng = Rng::new(42);
+    assert_eq!((0..5).map(|_| rng.next_u64()).collect::<Vec<_>>(), first);
+    assert!((0..1000).all(|_| (3..=7).contains(&rng.range(3..=7))));
+    let mut v = (0..10).collect::<Vec<_>>();
+    rng.shuffle(&mut v);
+    v.sort_unstable();
+    assert_eq!(v, (0..10).collect::<Vec<_>>());
+}
@@ -0,0 +1,139 @@
+use std::{fs, path::Path};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const GRID_TEMPLATE: &str = include_str!("../templates/grid.rs.tmpl");
+const ANSWERS_TEMPLATE: &str = include_str!("../templates/answers.toml.tmpl");
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{NN}}", &format!("{day:02}"))
+}
+
+/// Inserts `line` among the lines of `text` that `number_of` recognises,
+/// keeping them sorted by number.
+fn insert_sorted(
+    text: &str,
+    line: String,
+    day: u8,
+    number_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let mut lines = text.lines().map(str::to_owned).collect::<Vec<_>>();
+    let numbered = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, number_of(l)?)))
+        .collect::<Vec<_>>();
+    if numbered.iter().any(|&(_, n)| n == day) {
+        return Err(format!("day {day} is already registered"));
+    }
+    let at = match numbered.iter().find(|&&(_, n)| n > day) {
+        Some(&(i, _)) => i,
+        None => {
+            numbered
+                .last()
+                .ok_or("no day registered to insert after")?
+                .0
+                + 1
+        }
+    };
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `day` to the modules and to `DAYS` of `src/days/mod.rs`.
+pub fn register(mod_rs: &str, day: u8) -> Result<String, String> {
+    let mod_rs = insert_sorted(mod_rs, format!("pub mod day{day:02};"), day, |l| {
+        l.strip_prefix("pub mod day")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })?;
+    insert_sorted(
+        &mod_rs,
+        format!("    day!({day}, day{day:02}, Day{day:02}),"),
+        day,
+        |l| {
+            l.trim()
+                .strip_prefix("day!(")?
+                .split_once(',')?
+                .0
+                .parse()
+                .ok()
+        },
+    )
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Creates the source, input and examples of `day` under `root` and registers it.
+///
+/// Returns the created files.
+pub fn new_day(root: &Path, day: u8, grid: bool) -> Result<Vec<String>, String> {
+    let days_dir = root.join("src").join("days");
+    let source = days_dir.join(format!("day{day:02}.rs"));
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+    let mod_rs = days_dir.join("mod.rs");
+    let registered = register(
+        &fs::read_to_string(&mod_rs).map_err(|e| format!("{}: {e}", mod_rs.display()))?,
+        day,
+    )?;
+
+    let template = if grid { GRID_TEMPLATE } else { DAY_TEMPLATE };
+    let examples_dir = root.join("examples").join(format!("day{day:02}"));
+    let files = [
+        (source, render(template, day)),
+        (
+            days_dir.join(format!("day{day:02}")).join("input.txt"),
+            String::new(),
+        ),
+        (examples_dir.join("1.txt"), String::new()),
+        (
+            examples_dir.join("answers.toml"),
+            render(ANSWERS_TEMPLATE, day),
+        ),
+    ];
+    for (path, contents) in &files {
+        write_new(path, contents)?;
+    }
+    fs::write(&mod_rs, registered).map_err(|e| format!("{}: {e}", mod_rs.display()))?;
+    Ok(files
+        .iter()
+        .map(|(path, _)| path.display().to_string())
+        .collect())
+}
+
+#[test]
+fn test_register() {
+    let mod_rs = "pub mod day01;
+pub mod day12;
+
+pub const DAYS: &[Day] = &[
+    day!(1, day01, Day01),
+    day!(12, day12, Day12),
+];
+";
+    assert_eq!(
+        register(mod_rs, 3).unwrap(),
+        "pub mod day01;
+pub mod day03;
+pub mod day12;
+
+pub const DAYS: &[Day] = &[
+    day!(1, day01, Day01),
+    day!(3, day03, Day03),
+    day!(12, day12, Day12),
+];
+"
+    );
+    assert!(register(mod_rs, 13).unwrap().contains(
+        "pub mod day12;
+pub mod day13;"
+    ));
+    assert!(register(mod_rs, 12).is_err());
+}
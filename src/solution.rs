@@ -0,0 +1,35 @@
+use crate::parse::ParseError;
+
+/// Common type of every day's answer.
+pub type Answer = i64;
+
+/// A day's puzzle: the input is parsed once, then both parts are solved from it.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+}
+
+/// A parsed input, with its solution type erased so the runner can hold any day.
+pub trait Solve {
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+}
+
+struct Parsed<S: Solution>(S::Parsed);
+
+impl<S: Solution> Solve for Parsed<S> {
+    fn part1(&self) -> Answer {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Answer {
+        S::part2(&self.0)
+    }
+}
+
+pub fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solve>, ParseError> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
@@ -0,0 +1,225 @@
+use std::{collections::BTreeMap, fmt};
+use strum::IntoEnumIterator;
+
+use crate::coords::{Coords, Direction4, Direction8};
+use crate::grid::Map;
+
+/// A grid that only stores the tiles set, anywhere, the others being `default`.
+///
+/// Its bounds are those of the box around the tiles set, and grow with them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseMap<T> {
+    tiles: BTreeMap<Coords, T>,
+    default: T,
+    /// Top left and bottom right corners, both included
+    bounds: Option<(Coords, Coords)>,
+}
+
+/// `bounds` grown to take `coords` in.
+fn grow(bounds: Option<(Coords, Coords)>, coords: Coords) -> (Coords, Coords) {
+    match bounds {
+        Some((min, max)) => (
+            Coords {
+                line: min.line.min(coords.line),
+                col: min.col.min(coords.col),
+            },
+            Coords {
+                line: max.line.max(coords.line),
+                col: max.col.max(coords.col),
+            },
+        ),
+        None => (coords, coords),
+    }
+}
+
+impl<T> SparseMap<T> {
+    pub fn new(default: T) -> Self {
+        SparseMap {
+            tiles: BTreeMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// The tiles of `map` that aren't `default`, at the same coordinates.
+    pub fn from_map(map: Map<T>, default: T) -> Self
+    where
+        T: PartialEq,
+    {
+        let mut sparse = SparseMap::new(default);
+        for (line, tiles) in map.0.into_iter().enumerate() {
+            for (col, tile) in tiles.into_iter().enumerate() {
+                if tile != sparse.default {
+                    sparse.set(Coords::from((line, col)), tile);
+                }
+            }
+        }
+        sparse
+    }
+
+    /// Top left and bottom right corners of the tiles set, both included.
+    pub fn bounds(&self) -> Option<(Coords, Coords)> {
+        self.bounds
+    }
+
+    /// Number of columns in the bounds.
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.col - min.col + 1) as usize)
+    }
+
+    /// Number of lines in the bounds.
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.line - min.line + 1) as usize)
+    }
+
+    pub fn contains(&self, coords: Coords) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            (min.line..=max.line).contains(&coords.line)
+                && (min.col..=max.col).contains(&coords.col)
+        })
+    }
+
+    /// The tile at `coords`, `default` where none is set, or `None` out of the bounds.
+    pub fn get(&self, coords: Coords) -> Option<&T> {
+        if !self.contains(coords) {
+            return None;
+        }
+        Some(self.tiles.get(&coords).unwrap_or(&self.default))
+    }
+
+    /// Puts `value` at `coords`, growing the bounds if needed, and returns the tile set
+    /// there before.
+    pub fn set(&mut self, coords: Coords, value: T) -> Option<T> {
+        self.bounds = Some(grow(self.bounds, coords));
+        self.tiles.insert(coords, value)
+    }
+
+    /// Takes the tile at `coords` out, shrinking the bounds to the tiles left.
+    pub fn remove(&mut self, coords: Coords) -> Option<T> {
+        let removed = self.tiles.remove(&coords)?;
+        self.bounds = self
+            .tiles
+            .keys()
+            .fold(None, |bounds, &c| Some(grow(bounds, c)));
+        Some(removed)
+    }
+
+    /// Number of tiles set.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Every tile set with its coordinates, line by line.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (Coords, &T)> {
+        self.tiles.iter().map(|(&coords, tile)| (coords, tile))
+    }
+
+    fn around(
+        &self,
+        coords: Coords,
+        offsets: impl Iterator<Item = Coords>,
+    ) -> impl Iterator<Item = (Coords, &T)> {
+        offsets.filter_map(move |offset| {
+            let next = coords + offset;
+            Some((next, self.get(next)?))
+        })
+    }
+
+    /// The tiles up, right, down and left of `coords`, leaving out those out of the bounds.
+    pub fn neighbors4(&self, coords: Coords) -> impl Iterator<Item = (Coords, &T)> {
+        self.around(coords, Direction4::iter().map(Direction4::offset))
+    }
+
+    /// The 8 tiles around `coords` clockwise from up, leaving out those out of the bounds.
+    pub fn neighbors8(&self, coords: Coords) -> impl Iterator<Item = (Coords, &T)> {
+        self.around(coords, Direction8::iter().map(Direction8::offset))
+    }
+
+    /// The first tile set, line by line, that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(Coords, &T)> {
+        self.iter_coords().find(|(_, tile)| predicate(tile))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coords> {
+        self.find(predicate).map(|(coords, _)| coords)
+    }
+}
+
+impl<T: Clone> SparseMap<T> {
+    /// The bounds as a dense map, its top left corner moved to 0, 0.
+    pub fn to_map(&self) -> Map<T> {
+        let Some((min, max)) = self.bounds else {
+            return Map(Vec::new());
+        };
+        Map((min.line..=max.line)
+            .map(|line| {
+                (min.col..=max.col)
+                    .map(|col| self.get(Coords { line, col }).unwrap().clone())
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+impl<T: Default + PartialEq> From<Map<T>> for SparseMap<T> {
+    fn from(map: Map<T>) -> Self {
+        SparseMap::from_map(map, T::default())
+    }
+}
+
+/// The corners, then every tile in the bounds.
+impl<T: fmt::Debug> fmt::Debug for SparseMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return writeln!(f, "empty");
+        };
+        writeln!(f, "{},{} to {},{}", min.line, min.col, max.line, max.col)?;
+        for line in min.line..=max.line {
+            for col in min.col..=max.col {
+                write!(f, "{:?}", self.get(Coords { line, col }).unwrap())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_sparse_map() {
+    let at = |line, col| Coords { line, col };
+    let mut sparse = SparseMap::new('.');
+    assert_eq!(sparse.get(at(0, 0)), None);
+    sparse.set(at(-1_000_000, 3), 'a');
+    sparse.set(at(-999_999, 5), 'b');
+    assert_eq!((sparse.width(), sparse.height()), (3, 2));
+    assert_eq!(sparse.get(at(-1_000_000, 4)), Some(&'.'));
+    assert_eq!(sparse.get(at(-1_000_000, 6)), None);
+    assert_eq!(
+        sparse
+            .neighbors8(at(-1_000_000, 4))
+            .map(|(_, &c)| c)
+            .collect::<String>(),
+        ".b..a"
+    );
+    assert_eq!(
+        format!("{sparse:?}"),
+        "-1000000,3 to -999999,5\n'a''.''.'\n'.''.''b'\n"
+    );
+    assert_eq!(sparse.remove(at(-999_999, 5)), Some('b'));
+    assert_eq!(
+        sparse.bounds(),
+        Some((at(-1_000_000, 3), at(-1_000_000, 3)))
+    );
+
+    let map: Map<char> = "..#\n#..\n".parse().unwrap();
+    let sparse = SparseMap::from_map(Map(map.0.clone()), '.');
+    assert_eq!(sparse.len(), 2);
+    assert_eq!(sparse.position(|&c| c == '#'), Some(at(0, 2)));
+    assert!(sparse.to_map() == map);
+}
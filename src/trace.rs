@@ -0,0 +1,99 @@
+//! Diagnostics the days can leave in: events with named values, printed to stderr
+//! when the verbosity allows it.
+//!
+//! `debug!` events are shown from `-v`, once or a few times per part; `trace!` events from
+//! `-vv`, as often as in a loop.
+
+use std::{
+    cell::RefCell,
+    fmt::{self, Write as _},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+pub const DEBUG: u8 = 1;
+pub const TRACE: u8 = 2;
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    /// Events caught by `capture` on this thread, instead of printed
+    static CAPTURED: RefCell<Option<(u8, Vec<String>)>> = const { RefCell::new(None) };
+}
+
+/// Shows events up to `level`: 0 for none, `DEBUG` or `TRACE`.
+pub fn set_level(level: u8) {
+    LEVEL.store(level, Ordering::Relaxed);
+}
+
+pub fn enabled(level: u8) -> bool {
+    CAPTURED
+        .with_borrow(|c| c.as_ref().map(|(l, _)| *l))
+        .unwrap_or_else(|| LEVEL.load(Ordering::Relaxed))
+        >= level
+}
+
+/// Prints an event as `module name key=value ...`, the module being the last part of `module`.
+pub fn emit(module: &str, name: &str, fields: &[(&str, &dyn fmt::Debug)]) {
+    let mut line = format!("{} {name}", module.rsplit("::").next().unwrap_or(module));
+    for (key, value) in fields {
+        write!(line, " {key}={value:?}").unwrap();
+    }
+    let printed = CAPTURED.with_borrow_mut(|c| match c {
+        Some((_, lines)) => {
+            lines.push(line.clone());
+            false
+        }
+        None => true,
+    });
+    if printed {
+        eprintln!("{line}");
+    }
+}
+
+/// Runs `f` with events up to `level` on this thread collected, and returns them.
+pub fn capture(level: u8, f: impl FnOnce()) -> Vec<String> {
+    CAPTURED.set(Some((level, Vec::new())));
+    f();
+    CAPTURED.take().map(|(_, lines)| lines).unwrap_or_default()
+}
+
+/// An event of `level`, with a name and `key = value` fields printed with `Debug`.
+#[macro_export]
+macro_rules! event {
+    ($level:expr, $name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit(
+                module_path!(),
+                $name,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Debug)),*],
+            );
+        }
+    };
+}
+
+/// An event shown from `-v`.
+#[macro_export]
+macro_rules! debug {
+    ($($event:tt)*) => {
+        $crate::event!($crate::trace::DEBUG, $($event)*)
+    };
+}
+
+/// An event shown from `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($event:tt)*) => {
+        $crate::event!($crate::trace::TRACE, $($event)*)
+    };
+}
+
+#[test]
+fn test_capture() {
+    let hits = 3;
+    let lines = capture(DEBUG, || {
+        crate::debug!("cache", hits = hits, misses = 4);
+        crate::trace!("step");
+    });
+    assert_eq!(lines, ["trace cache hits=3 misses=4"]);
+    assert!(!enabled(DEBUG));
+}
@@ -0,0 +1,19 @@
+use advent_of_code_2023::days::{self, day06::Day06, day11::Day11};
+use advent_of_code_2023::solution::Solution;
+
+#[test]
+fn test_solver_directly() {
+    let races = Day06::parse("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+    assert_eq!(Day06::part1(&races), 288);
+    assert_eq!(Day06::part2(&races), 71503);
+
+    let universe = Day11::parse(include_str!("../examples/day11/1.txt")).unwrap();
+    assert_eq!(Day11::part1(&universe), 374);
+}
+
+#[test]
+fn test_registry() {
+    let day = days::get(9).unwrap();
+    let parsed = (day.parse)("0 3 6 9 12 15\n").unwrap();
+    assert_eq!((parsed.part1(), parsed.part2()), (18, -3));
+}